@@ -0,0 +1,411 @@
+//! A blocking client for the EDBO Registry API.
+//!
+//! This mirrors [`crate::EdboClient`] for code that does not run inside an async runtime.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::thread;
+use std::time::Duration;
+use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::codec::OneOrMany;
+use crate::error::Error;
+use crate::model::{Institution, Region, University, UniversityBrief, UniversityCategory};
+use crate::search::{Operation, SearchParams};
+use crate::retry::RetryPolicy;
+use crate::cache::DiskCache;
+use crate::conditional::Validators;
+use crate::memory::MemoryCache;
+use crate::metrics::{EdboMetrics, Metrics};
+use crate::ratelimit::RateLimiter;
+use crate::page::Page;
+use crate::provenance::SearchResult;
+use crate::sweep::SweepResult;
+use crate::collation::{dedup_universities, sort_universities};
+use crate::client::{check_id, non_empty};
+use crate::{EdboClientBuilder, DEFAULT_MAX_CONCURRENCY, DEFAULT_MAX_ID};
+use crate::{make_request_blocking, BASE_URL};
+
+/// A blocking client for the EDBO Registry API.
+///
+/// `EdboClient` owns a single `reqwest::blocking::Client`, so every request made through it
+/// shares the same connection pool.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use libedbo::{SearchParams, Region, UniversityCategory};
+/// use libedbo::blocking::EdboClient;
+///
+/// # fn main() -> Result<(), libedbo::error::Error> {
+/// let client = EdboClient::new();
+/// let params = SearchParams::new()
+///     .with_region(Region::LvivOblast)
+///     .with_university_category(UniversityCategory::HigherEducationInstitutions);
+///
+/// let universities = client.search_universities(params)?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdboClient {
+  http: Client,
+  base_url: String,
+  retry: RetryPolicy,
+  max_concurrency: usize,
+  max_id: i32,
+  cache: Option<DiskCache>,
+  memory: Option<Arc<MemoryCache>>,
+  metrics: Arc<Metrics>,
+  rate_limiter: Option<Arc<RateLimiter>>,
+  sort_results: bool,
+  validators: Option<Arc<Validators>>,
+}
+
+impl Default for EdboClient {
+  fn default() -> Self {
+    EdboClient::new()
+  }
+}
+
+impl EdboClient {
+  /// Creates a new client with a default `reqwest::blocking::Client` pointed at the official registry.
+  pub fn new() -> Self {
+    EdboClient {
+      http: Client::new(),
+      base_url: BASE_URL.to_string(),
+      retry: RetryPolicy::default(),
+      max_concurrency: DEFAULT_MAX_CONCURRENCY,
+      max_id: DEFAULT_MAX_ID,
+      cache: None,
+      memory: None,
+      metrics: Arc::default(),
+      rate_limiter: None,
+      sort_results: false,
+      validators: None,
+    }
+  }
+
+  /// Returns a builder for configuring a client.
+  ///
+  /// Finish the builder with [`EdboClientBuilder::build_blocking`] to obtain a blocking client.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::blocking::EdboClient;
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::builder()
+  ///     .base_url("http://localhost:8080")
+  ///     .build_blocking()?;
+  /// assert_eq!(client.base_url(), "http://localhost:8080");
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn builder() -> EdboClientBuilder {
+    EdboClientBuilder::new()
+  }
+
+  pub(crate) fn from_builder(http: Client, builder: &EdboClientBuilder) -> Self {
+    EdboClient {
+      http,
+      base_url: builder.resolved_base_url(),
+      retry: builder.retry_policy(),
+      max_concurrency: builder.resolved_max_concurrency(),
+      max_id: builder.resolved_max_id(),
+      cache: builder.disk_cache(),
+      memory: builder.memory_cache(),
+      metrics: Arc::default(),
+      rate_limiter: builder.rate_limiter(),
+      sort_results: builder.sort_results,
+      validators: builder.conditional_requests.then(Arc::default),
+    }
+  }
+
+  /// Returns the base URL that endpoint paths are joined onto.
+  pub fn base_url(&self) -> &str {
+    &self.base_url
+  }
+
+  /// Returns the fraction of single-record lookups answered from the memory cache.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::memory_cache_hit_ratio`].
+  pub fn memory_cache_hit_ratio(&self) -> Option<f64> {
+    self.memory.as_ref()?.hit_ratio()
+  }
+
+  /// Returns a snapshot of the request, error and cache counters of this client and its clones.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::metrics`].
+  pub fn metrics(&self) -> EdboMetrics {
+    let memory = self.memory.as_deref();
+    self.metrics.snapshot(memory.map_or(0, MemoryCache::hits), memory.map_or(0, MemoryCache::misses))
+  }
+
+  /// Returns a client that shares this one's connection pool and options but neither reads
+  /// from nor writes to the response cache or the memory cache.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::uncached`].
+  pub fn uncached(&self) -> EdboClient {
+    EdboClient { cache: None, memory: None, ..self.clone() }
+  }
+
+  /// Returns the lazily-initialized client used by the free blocking search functions, built
+  /// with [`EdboClientBuilder::shared`].
+  pub(crate) fn shared() -> &'static EdboClient {
+    static SHARED: OnceLock<EdboClient> = OnceLock::new();
+    SHARED.get_or_init(|| EdboClientBuilder::shared().build_blocking().unwrap_or_else(|_| EdboClient::new()))
+  }
+
+  /// Sends a GET request, retrying transient failures according to the client's retry policy.
+  ///
+  /// A fresh cached response is returned without a request, and a successful response is cached.
+  /// A `timeout` applies to each attempt on its own, in place of the client-wide timeout.
+  /// Errors are wrapped in `Error::Request` carrying `url`.
+  fn get<T: DeserializeOwned>(&self, url: String, timeout: Option<Duration>) -> Result<T, Error> {
+    if let Some(cache) = &self.cache {
+      let cached = cache.get(&url);
+      self.metrics.cache_lookup(cached.is_some());
+      if let Some(value) = cached {
+        return serde_json::from_value(value).map_err(|err| Error::from(err).in_request(&url));
+      }
+    }
+    let mut attempt = 0;
+    loop {
+      if let Some(rate_limiter) = &self.rate_limiter {
+        thread::sleep(rate_limiter.reserve());
+      }
+      self.metrics.request();
+      let response = make_request_blocking(&self.http, url.clone(), self.validators.as_deref(), timeout);
+      self.metrics.record(&response);
+      match response {
+        Ok(value) => {
+          let parsed = T::deserialize(&value).map_err(|err| Error::from(err).in_request(&url))?;
+          if let Some(cache) = &self.cache {
+            cache.put(&url, &value);
+          }
+          return Ok(parsed);
+        }
+        Err(err) => match self.retry.delay(attempt, &err) {
+          Some(delay) => thread::sleep(delay),
+          None => return Err(err.in_request(&url)),
+        },
+      }
+      attempt += 1;
+    }
+  }
+
+  /// Fetches the single record that `operation` names by ID, going through the memory cache.
+  fn get_record<T>(&self, operation: Operation, param: &SearchParams, kind: &'static str, timeout: Option<Duration>) -> Result<T, Error>
+  where
+    T: DeserializeOwned + Clone + Send + Sync + 'static,
+  {
+    let url = param.url_for(operation, &self.base_url)?;
+    check_id(param.id, self.max_id)?;
+    let cached = self.memory.as_ref().zip(param.id);
+    if let Some(record) = cached.and_then(|(memory, id)| memory.get(operation.endpoint(), id)) {
+      return Ok(record);
+    }
+    let record: T = self.get(url, timeout).map_err(|err| err.for_resource(kind, param.id))?;
+    if let Some((memory, id)) = cached {
+      memory.put(operation.endpoint(), id, record.clone());
+    }
+    Ok(record)
+  }
+
+  /// Searches for universities based on provided parameters.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_universities`].
+  pub fn search_universities(&self, param: SearchParams) -> Result<Vec<UniversityBrief>, Error> {
+    let url = param.url_for(Operation::Universities, &self.base_url)?;
+    let OneOrMany(mut universities) = self.get(url, None)?;
+    universities.retain(|uni| param.matches_university(uni));
+    if self.sort_results {
+      dedup_universities(&mut universities);
+      sort_universities(&mut universities);
+    }
+    Ok(universities)
+  }
+
+  /// Returns page `page` (zero-based) of `page_size` universities, together with the total
+  /// number of universities matching `param`.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_universities_page`].
+  pub fn search_universities_page(&self, param: SearchParams, page: usize, page_size: usize) -> Result<Page<UniversityBrief>, Error> {
+    Page::from_records(self.search_universities(param)?, page, page_size)
+  }
+
+  /// Searches for universities, treating an empty result as `Error::EmptyResult`.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_universities_non_empty`].
+  pub fn search_universities_non_empty(&self, param: SearchParams) -> Result<Vec<UniversityBrief>, Error> {
+    non_empty(self.search_universities(param)?)
+  }
+
+  /// Retrieves detailed information about a specific university.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_university`].
+  pub fn search_university(&self, param: SearchParams) -> Result<University, Error> {
+    self.get_record(Operation::University, &param, "university", None)
+  }
+
+  /// Retrieves detailed information about a specific university, with a timeout for this call
+  /// only.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_university_with_timeout`].
+  pub fn search_university_with_timeout(&self, param: SearchParams, timeout: Duration) -> Result<University, Error> {
+    self.get_record(Operation::University, &param, "university", Some(timeout))
+  }
+
+  /// Retrieves the listing-level summary of a university by ID.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::university_brief`], and likewise
+  /// downloads the full record.
+  pub fn university_brief(&self, id: impl Into<i32>) -> Result<UniversityBrief, Error> {
+    self.search_university(SearchParams::new().with_id(id)).map(UniversityBrief::from)
+  }
+
+  /// Retrieves a university's record as the registry's JSON.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::university_json`].
+  pub fn university_json(&self, id: impl Into<i32>) -> Result<Value, Error> {
+    let param = SearchParams::new().with_id(id);
+    let url = param.url_for(Operation::University, &self.base_url)?;
+    check_id(param.id, self.max_id)?;
+    self.get(url, None).map_err(|err| err.for_resource("university", param.id))
+  }
+
+  /// Lists the universities of `category` in every region of Ukraine, fetching several regions
+  /// at once on worker threads.
+  ///
+  /// At most [`EdboClientBuilder::max_concurrency`] threads are started, each taking the next
+  /// region that has not been fetched yet. The results are combined as in
+  /// [`crate::EdboClient::search_all_regions`], of which this is the blocking version.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::UniversityCategory;
+  /// use libedbo::blocking::EdboClient;
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::builder().max_concurrency(4).build_blocking()?;
+  /// let universities = client.search_all_regions(UniversityCategory::HigherEducationInstitutions).into_result()?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn search_all_regions(&self, category: UniversityCategory) -> SweepResult {
+    let next = AtomicUsize::new(0);
+    let mut responses: Vec<_> = thread::scope(|scope| {
+      let workers: Vec<_> = (0..self.max_concurrency.min(Region::ALL.len()))
+        .map(|_| {
+          scope.spawn(|| {
+            let mut fetched = Vec::new();
+            loop {
+              let index = next.fetch_add(1, Ordering::Relaxed);
+              let Some(&region) = Region::ALL.get(index) else { break };
+              let params = SearchParams::new().with_region(region).with_university_category(category);
+              fetched.push((index, self.search_universities(params)));
+            }
+            fetched
+          })
+        })
+        .collect();
+      workers
+        .into_iter()
+        .flat_map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+        .collect()
+    });
+    responses.sort_by_key(|(index, _)| *index);
+    SweepResult::collect(responses.into_iter().map(|(_, response)| response), self.sort_results)
+  }
+
+  /// Searches for secondary education institutions based on provided parameters.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_institutions`].
+  pub fn search_institutions(&self, param: SearchParams) -> Result<Vec<Institution>, Error> {
+    let url = param.url_for(Operation::Institutions, &self.base_url)?;
+    let OneOrMany(mut institutions) = self.get(url, None)?;
+    institutions.retain(|institution| param.matches_institution(institution));
+    Ok(institutions)
+  }
+
+  /// Retrieves detailed information about a specific school.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_school`].
+  pub fn search_school(&self, param: SearchParams) -> Result<Institution, Error> {
+    self.get_record(Operation::School, &param, "school", None)
+  }
+
+  /// Retrieves detailed information about a specific school, with a timeout for this call only.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_school_with_timeout`].
+  pub fn search_school_with_timeout(&self, param: SearchParams, timeout: Duration) -> Result<Institution, Error> {
+    self.get_record(Operation::School, &param, "school", Some(timeout))
+  }
+
+  /// Retrieves a school's record as the registry's JSON.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::school_json`].
+  pub fn school_json(&self, id: impl Into<i32>) -> Result<Value, Error> {
+    let param = SearchParams::new().with_id(id);
+    let url = param.url_for(Operation::School, &self.base_url)?;
+    check_id(param.id, self.max_id)?;
+    self.get(url, None).map_err(|err| err.for_resource("school", param.id))
+  }
+
+  /// Lists universities, bundling the result with `param` and the time the search returned.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_universities_with_meta`].
+  pub fn search_universities_with_meta(&self, param: SearchParams) -> Result<SearchResult<Vec<UniversityBrief>>, Error> {
+    let data = self.search_universities(param.clone())?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Fetches a university, bundling it with `param` and the time the search returned.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_university_with_meta`].
+  pub fn search_university_with_meta(&self, param: SearchParams) -> Result<SearchResult<University>, Error> {
+    let data = self.search_university(param.clone())?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Lists institutions, bundling the result with `param` and the time the search returned.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_institutions_with_meta`].
+  pub fn search_institutions_with_meta(&self, param: SearchParams) -> Result<SearchResult<Vec<Institution>>, Error> {
+    let data = self.search_institutions(param.clone())?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Fetches a school, bundling it with `param` and the time the search returned.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::search_school_with_meta`].
+  pub fn search_school_with_meta(&self, param: SearchParams) -> Result<SearchResult<Institution>, Error> {
+    let data = self.search_school(param.clone())?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Fetches the parent of `university`, if it is a branch of another university.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::fetch_parent`].
+  pub fn fetch_parent(&self, university: &University) -> Result<Option<University>, Error> {
+    match university.parent_id() {
+      Some(id) => self.search_university(SearchParams::new().with_id(id)).map(Some),
+      None => Ok(None),
+    }
+  }
+
+  /// Fetches the institution `institution` belongs to, if it has a parent.
+  ///
+  /// This is the blocking version of [`crate::EdboClient::fetch_parent_institution`].
+  pub fn fetch_parent_institution(&self, institution: &Institution) -> Result<Option<Institution>, Error> {
+    match institution.parent_id() {
+      Some(id) => self.search_school(SearchParams::new().with_id(id)).map(Some),
+      None => Ok(None),
+    }
+  }
+}
@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use serde_json::Value;
+
+/// How long cached responses stay fresh when only a cache directory is configured.
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A directory of raw JSON responses, keyed by request URL.
+///
+/// The cache is best-effort: unreadable, corrupt or expired entries count as misses, and a
+/// failure to write an entry does not fail the request that produced it.
+#[derive(Debug, Clone)]
+pub(crate) struct DiskCache {
+  dir: PathBuf,
+  ttl: Duration,
+}
+
+impl DiskCache {
+  pub(crate) fn new(dir: PathBuf, ttl: Duration) -> Self {
+    DiskCache { dir, ttl }
+  }
+
+  /// Returns the cached response for `url` if it was stored less than the TTL ago.
+  pub(crate) fn get(&self, url: &str) -> Option<Value> {
+    let path = self.path_for(url);
+    let age = fs::metadata(&path).ok()?.modified().ok()?.elapsed().unwrap_or_default();
+    if age >= self.ttl {
+      return None;
+    }
+    serde_json::from_slice(&fs::read(&path).ok()?).ok()
+  }
+
+  /// Stores the response for `url`, replacing any previous entry.
+  pub(crate) fn put(&self, url: &str, value: &Value) {
+    let path = self.path_for(url);
+    let _ = fs::create_dir_all(&self.dir).and_then(|_| write_atomically(&path, value));
+  }
+
+  /// Names an entry after a stable hash of its URL, so it survives across processes and
+  /// compiler versions.
+  fn path_for(&self, url: &str) -> PathBuf {
+    self.dir.join(format!("{:016x}.json", fnv1a(url.as_bytes())))
+  }
+}
+
+/// Writes `value` to a temporary file next to `path` and renames it into place, so readers
+/// never see a partially written entry.
+fn write_atomically(path: &Path, value: &Value) -> std::io::Result<()> {
+  let nanos = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().subsec_nanos();
+  let tmp = path.with_extension(format!("{}.{nanos}.tmp", std::process::id()));
+  fs::write(&tmp, serde_json::to_vec(value)?)?;
+  fs::rename(&tmp, path).inspect_err(|_| {
+    let _ = fs::remove_file(&tmp);
+  })
+}
+
+/// The 64-bit FNV-1a hash.
+fn fnv1a(bytes: &[u8]) -> u64 {
+  bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3))
+}
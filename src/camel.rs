@@ -0,0 +1,64 @@
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+use serde_json::{Map, Value};
+
+/// Serializes the wrapped value with every object key in camelCase instead of snake_case.
+///
+/// The models read and write the registry's snake_case keys, which is what deserialization
+/// needs. Wrap a record, a reference to one or a list of them in `CamelCase` when handing them
+/// to a JavaScript front-end instead. Keys of nested objects, such as a university's
+/// `branches`, are converted too; values are left untouched.
+///
+/// The value passes through a `serde_json::Value` on the way, so object keys come out in
+/// alphabetical order rather than in field order.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::CamelCase;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Row {
+///     university_name: &'static str,
+///     post_index_u: &'static str,
+/// }
+///
+/// let row = Row { university_name: "КНУ", post_index_u: "01033" };
+/// let json = serde_json::to_string(&CamelCase(&row)).unwrap();
+/// assert_eq!(json, r#"{"postIndexU":"01033","universityName":"КНУ"}"#);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CamelCase<T>(pub T);
+
+impl<T: Serialize> Serialize for CamelCase<T> {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    let value = serde_json::to_value(&self.0).map_err(S::Error::custom)?;
+    camel_case_keys(value).serialize(serializer)
+  }
+}
+
+/// Renames the keys of every object in `value` to camelCase.
+fn camel_case_keys(value: Value) -> Value {
+  match value {
+    Value::Object(object) => Value::Object(
+      object.into_iter().map(|(key, value)| (camel_case(&key), camel_case_keys(value))).collect::<Map<_, _>>(),
+    ),
+    Value::Array(items) => Value::Array(items.into_iter().map(camel_case_keys).collect()),
+    other => other,
+  }
+}
+
+/// Converts a snake_case key to camelCase, e.g. `post_index_u` to `postIndexU`.
+fn camel_case(key: &str) -> String {
+  let mut parts = key.split('_').filter(|part| !part.is_empty());
+  let mut camel = parts.next().unwrap_or_default().to_string();
+  for part in parts {
+    let mut chars = part.chars();
+    if let Some(first) = chars.next() {
+      camel.extend(first.to_uppercase());
+      camel.push_str(chars.as_str());
+    }
+  }
+  camel
+}
@@ -0,0 +1,1521 @@
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+use std::collections::HashSet;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Client;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Proxy;
+use tokio::sync::Semaphore;
+use tokio_util::sync::CancellationToken;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::codec::OneOrMany;
+use crate::error::Error;
+use crate::model::{Institution, InstitutionCategory, Region, University, UniversityBrief, UniversityCategory};
+use crate::search::{Operation, SearchParams};
+#[cfg(feature = "blocking")]
+use crate::blocking;
+use crate::retry::{self, RetryPolicy, DEFAULT_RETRY_BASE_DELAY};
+use crate::transport::{HttpTransport, ReqwestTransport};
+use crate::cache::{DiskCache, DEFAULT_CACHE_TTL};
+use crate::memory::MemoryCache;
+use crate::metrics::{EdboMetrics, Metrics};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::ratelimit::RateLimiter;
+use crate::page::Page;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::provenance::SearchResult;
+use crate::sweep::SweepResult;
+use crate::tree::UniversityTree;
+use crate::collation::{dedup_institutions, dedup_universities, sort_universities};
+use crate::BASE_URL;
+
+/// The default number of requests a batch operation keeps in flight at once.
+pub const DEFAULT_MAX_CONCURRENCY: usize = 8;
+
+/// The default upper bound on the IDs a client will request.
+///
+/// University IDs are in the tens of thousands and school IDs in the hundreds of thousands,
+/// so anything above this is a typo rather than a record.
+pub const DEFAULT_MAX_ID: i32 = 1_000_000;
+
+/// The `User-Agent` the free search functions identify themselves with.
+const SHARED_USER_AGENT: &str = concat!("libedbo/", env!("CARGO_PKG_VERSION"));
+
+/// The total request timeout of the client behind the free search functions.
+#[cfg(not(target_arch = "wasm32"))]
+const SHARED_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// A builder for configuring an [`EdboClient`] or, with the `blocking` feature, a
+/// `blocking::EdboClient`.
+///
+/// Every option falls back to the behavior of the free functions when it is not set.
+///
+/// With the default `compression` feature, clients advertise gzip and brotli support in
+/// `Accept-Encoding` and transparently decompress responses.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use libedbo::EdboClient;
+///
+/// # fn main() -> Result<(), libedbo::error::Error> {
+/// let client = EdboClient::builder()
+///     .base_url("http://localhost:8080")
+///     .timeout(Duration::from_secs(10))
+///     .connect_timeout(Duration::from_secs(3))
+///     .retries(3)
+///     .retry_base_delay(Duration::from_millis(200))
+///     .header("X-Api-Key", "secret")
+///     .user_agent("myapp/1.0")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EdboClientBuilder {
+  base_url: Option<String>,
+  #[cfg(not(target_arch = "wasm32"))]
+  timeout: Option<Duration>,
+  #[cfg(not(target_arch = "wasm32"))]
+  connect_timeout: Option<Duration>,
+  retries: u32,
+  retry_base_delay: Option<Duration>,
+  max_concurrency: Option<usize>,
+  max_id: Option<i32>,
+  #[cfg(not(target_arch = "wasm32"))]
+  rate_limit: u32,
+  headers: Vec<(String, String)>,
+  user_agent: Option<String>,
+  #[cfg(not(target_arch = "wasm32"))]
+  proxies: Vec<Proxy>,
+  #[cfg(not(target_arch = "wasm32"))]
+  no_proxy: bool,
+  cache_dir: Option<PathBuf>,
+  cache_ttl: Option<Duration>,
+  pub(crate) sort_results: bool,
+  memory_cache_capacity: usize,
+  pub(crate) conditional_requests: bool,
+  http: Option<Client>,
+  transport: Option<Arc<dyn HttpTransport>>,
+}
+
+impl EdboClientBuilder {
+  /// Returns a builder with the options of the client behind the free search functions: a
+  /// `libedbo/<version>` user agent and, outside wasm32, a 30 second request timeout.
+  pub(crate) fn shared() -> Self {
+    let builder = EdboClientBuilder::new().user_agent(SHARED_USER_AGENT);
+    #[cfg(not(target_arch = "wasm32"))]
+    let builder = builder.timeout(SHARED_TIMEOUT);
+    builder
+  }
+
+  /// Creates a builder with every option unset.
+  pub fn new() -> Self {
+    EdboClientBuilder {
+      base_url: None,
+      #[cfg(not(target_arch = "wasm32"))]
+      timeout: None,
+      #[cfg(not(target_arch = "wasm32"))]
+      connect_timeout: None,
+      retries: 0,
+      retry_base_delay: None,
+      max_concurrency: None,
+      max_id: None,
+      #[cfg(not(target_arch = "wasm32"))]
+      rate_limit: 0,
+      headers: Vec::new(),
+      user_agent: None,
+      #[cfg(not(target_arch = "wasm32"))]
+      proxies: Vec::new(),
+      #[cfg(not(target_arch = "wasm32"))]
+      no_proxy: false,
+      cache_dir: None,
+      cache_ttl: None,
+      sort_results: false,
+      memory_cache_capacity: 0,
+      conditional_requests: false,
+      http: None,
+      transport: None,
+    }
+  }
+
+  /// Sets the base URL that endpoint paths are joined onto.
+  ///
+  /// Defaults to the official registry, `https://registry.edbo.gov.ua`.
+  pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+    self.base_url = Some(base_url.into());
+    self
+  }
+
+  /// Sets the total timeout for each request, from connecting until the body has been read.
+  ///
+  /// A request that exceeds it fails with [`Error::Timeout`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn timeout(mut self, timeout: Duration) -> Self {
+    self.timeout = Some(timeout);
+    self
+  }
+
+  /// Sets the timeout for establishing a connection to the registry.
+  ///
+  /// A connection attempt that exceeds it fails with [`Error::Timeout`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+    self.connect_timeout = Some(connect_timeout);
+    self
+  }
+
+  /// Sets how many times a failed request is retried before its error is returned.
+  ///
+  /// Only network errors, timeouts, 5xx responses and 429 responses are retried; other 4xx
+  /// responses are returned immediately. A 429 response waits for the delay in its
+  /// `Retry-After` header instead of the backoff schedule. Once the retries are exhausted, or
+  /// when they are disabled, a 429 response is returned as [`Error::RateLimited`].
+  ///
+  /// Defaults to 0, which disables retries.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use std::sync::Arc;
+  /// use std::sync::atomic::{AtomicU32, Ordering};
+  /// use std::time::Duration;
+  /// use libedbo::{EdboClient, HttpTransport, Region, SearchParams, TransportFuture};
+  /// use libedbo::error::Error;
+  /// use serde_json::json;
+  ///
+  /// /// Answers 503 to the first two requests and an empty listing after that.
+  /// #[derive(Debug, Clone, Default)]
+  /// struct Flaky(Arc<AtomicU32>);
+  ///
+  /// impl HttpTransport for Flaky {
+  ///     fn get_json<'a>(&'a self, _url: &'a str) -> TransportFuture<'a> {
+  ///         let call = self.0.fetch_add(1, Ordering::SeqCst) + 1;
+  ///         Box::pin(async move {
+  ///             if call <= 2 {
+  ///                 Err(Error::ApiError { status: 503, body: "unavailable".to_string() })
+  ///             } else {
+  ///                 Ok(json!([]))
+  ///             }
+  ///         })
+  ///     }
+  /// }
+  ///
+  /// #[tokio::main]
+  /// async fn main() -> Result<(), Error> {
+  ///     let flaky = Flaky::default();
+  ///     let client = EdboClient::builder().transport(flaky.clone()).retries(3).retry_base_delay(Duration::ZERO).build()?;
+  ///     assert!(client.search_universities(SearchParams::universities(Region::LvivOblast)).await?.is_empty());
+  ///     assert_eq!(flaky.0.load(Ordering::SeqCst), 3);
+  ///
+  ///     // With too few retries, the error of the last attempt is returned.
+  ///     let client = EdboClient::builder().transport(Flaky::default()).retries(1).retry_base_delay(Duration::ZERO).build()?;
+  ///     let err = client.search_universities(SearchParams::universities(Region::LvivOblast)).await.unwrap_err();
+  ///     assert!(matches!(err, Error::Request { .. }));
+  ///     assert!(matches!(err.inner(), Error::ApiError { status: 503, .. }));
+  ///     Ok(())
+  /// }
+  /// ```
+  pub fn retries(mut self, retries: u32) -> Self {
+    self.retries = retries;
+    self
+  }
+
+  /// Sets the delay before the first retry. Each further retry waits twice as long as the
+  /// previous one, minus a random jitter.
+  ///
+  /// Defaults to 200 milliseconds.
+  pub fn retry_base_delay(mut self, retry_base_delay: Duration) -> Self {
+    self.retry_base_delay = Some(retry_base_delay);
+    self
+  }
+
+  /// Sets how many requests the client keeps in flight at once.
+  ///
+  /// The limit is shared by every request made through the client and its clones, so
+  /// concurrent batch operations such as [`EdboClient::search_universities_by_ids`] and
+  /// [`EdboClient::search_all_regions`] together never exceed it. A request holds its slot
+  /// only while it is being sent, not while waiting to be retried, and responses served from
+  /// the cache do not take one.
+  ///
+  /// Defaults to [`DEFAULT_MAX_CONCURRENCY`]. Values below 1 are treated as 1. The blocking
+  /// client sends one request at a time, except in
+  /// [`blocking::EdboClient::search_all_regions`](crate::blocking::EdboClient::search_all_regions),
+  /// which runs this many worker threads.
+  pub fn max_concurrency(mut self, max_concurrency: usize) -> Self {
+    self.max_concurrency = Some(max_concurrency);
+    self
+  }
+
+  /// Sets the largest ID that single-record lookups will request.
+  ///
+  /// A larger ID is rejected with `Error::InvalidId` before any request is sent, instead of
+  /// costing a round trip that ends in a 404. IDs below 1 are always rejected. Defaults to
+  /// [`DEFAULT_MAX_ID`]; raise it if the registry ever outgrows it.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{EdboClient, SearchParams};
+  /// use libedbo::error::Error;
+  ///
+  /// # #[tokio::main]
+  /// # async fn main() -> Result<(), Error> {
+  /// let client = EdboClient::builder().max_id(50_000).build()?;
+  /// let err = client.search_university(SearchParams::by_id(999_999)).await.unwrap_err();
+  /// assert!(matches!(err, Error::InvalidId { id: 999_999, max: 50_000 }));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn max_id(mut self, max_id: i32) -> Self {
+    self.max_id = Some(max_id);
+    self
+  }
+
+  /// Limits the client to `requests_per_second` requests per second, to stay clear of the
+  /// registry's throttling rather than recovering from it with retries.
+  ///
+  /// Requests are spaced evenly: with a limit of 5, one request may start every 200
+  /// milliseconds. Requests over the limit wait their turn in arrival order. The limit covers
+  /// every request made through the client and its clones, including retries; responses served
+  /// from a cache are not counted. Unlike [`EdboClientBuilder::max_concurrency`], this also
+  /// applies to the blocking client.
+  ///
+  /// Defaults to 0, which disables the limit.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::EdboClient;
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::builder().rate_limit(5).max_concurrency(4).build()?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn rate_limit(mut self, requests_per_second: u32) -> Self {
+    self.rate_limit = requests_per_second;
+    self
+  }
+
+  /// Adds a header that is sent with every request, e.g. an API key expected by a gateway.
+  ///
+  /// Setting the same header twice sends both values. Invalid names or values are reported
+  /// by [`EdboClientBuilder::build`].
+  pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+    self.headers.push((name.into(), value.into()));
+    self
+  }
+
+  /// Sets the `User-Agent` header sent with every request.
+  pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+    self.user_agent = Some(user_agent.into());
+    self
+  }
+
+  /// Routes requests through `proxy`.
+  ///
+  /// Without an explicit proxy, the standard `HTTP_PROXY`/`HTTPS_PROXY`/`ALL_PROXY`
+  /// environment variables are respected. SOCKS5 proxies (`socks5://` URLs) need the `socks`
+  /// feature.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::EdboClient;
+  ///
+  /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+  /// let client = EdboClient::builder()
+  ///     .proxy(reqwest::Proxy::https("http://proxy.example.com:3128")?)
+  ///     .build()?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn proxy(mut self, proxy: Proxy) -> Self {
+    self.proxies.push(proxy);
+    self
+  }
+
+  /// Disables all proxies, including those configured through environment variables.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn no_proxy(mut self) -> Self {
+    self.no_proxy = true;
+    self
+  }
+
+  /// Caches successful responses as JSON files in `cache_dir`, keyed by request URL.
+  ///
+  /// While an entry is younger than the [cache TTL](EdboClientBuilder::cache_ttl), the same
+  /// request is answered from disk without touching the network. Failed requests are never
+  /// cached. The directory is created on the first write, and entries that cannot be read or
+  /// written are silently treated as misses. Use [`EdboClient::uncached`] to bypass the cache
+  /// for individual calls.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use std::time::Duration;
+  /// use libedbo::EdboClient;
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::builder()
+  ///     .cache_dir(std::env::temp_dir().join("edbo"))
+  ///     .cache_ttl(Duration::from_secs(86400))
+  ///     .build()?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+    self.cache_dir = Some(cache_dir.into());
+    self
+  }
+
+  /// Sets how long a cached response is served before the registry is asked again.
+  ///
+  /// Defaults to [`DEFAULT_CACHE_TTL`], one day. Has no effect unless
+  /// [`EdboClientBuilder::cache_dir`] is set.
+  pub fn cache_ttl(mut self, cache_ttl: Duration) -> Self {
+    self.cache_ttl = Some(cache_ttl);
+    self
+  }
+
+  /// Keeps up to `capacity` fetched universities and schools in memory, so that looking the
+  /// same ID up again returns a copy of the record without a request or a parse.
+  ///
+  /// The least recently used record is evicted when the cache is full. Records are keyed by
+  /// endpoint and ID and are kept until evicted; unlike [`EdboClientBuilder::cache_dir`],
+  /// there is no expiry. Listings are not cached in memory. Clones of the client share the
+  /// cache, and [`EdboClient::memory_cache_hit_ratio`] reports how well it is doing.
+  ///
+  /// Defaults to 0, which disables the memory cache.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::EdboClient;
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::builder().memory_cache_capacity(256).build()?;
+  /// assert_eq!(client.memory_cache_hit_ratio(), None);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn memory_cache_capacity(mut self, capacity: usize) -> Self {
+    self.memory_cache_capacity = capacity;
+    self
+  }
+
+  /// Makes university listings deterministic: results are deduplicated by university ID and
+  /// sorted by name in Ukrainian alphabetical order (see [`ukrainian_cmp`](crate::ukrainian_cmp)),
+  /// with ties broken by ID.
+  ///
+  /// This applies to [`EdboClient::search_universities`] and [`EdboClient::search_all_regions`].
+  /// Defaults to `false`, which keeps the order the registry returns.
+  pub fn sort_results(mut self, sort_results: bool) -> Self {
+    self.sort_results = sort_results;
+    self
+  }
+
+  /// Remembers the `ETag` and `Last-Modified` validators of responses and sends them back as
+  /// `If-None-Match` and `If-Modified-Since` when the same URL is requested again. A
+  /// `304 Not Modified` answer is then served from the remembered body, saving the download.
+  ///
+  /// Responses are remembered in memory for the lifetime of the client, and only when the
+  /// registry sends a validator with them; if it sends none, this option changes nothing. It
+  /// complements [`EdboClientBuilder::cache_dir`], whose fresh entries are used without asking
+  /// the registry at all. It has no effect with a custom [`EdboClientBuilder::transport`].
+  ///
+  /// Defaults to `false`.
+  pub fn conditional_requests(mut self, conditional_requests: bool) -> Self {
+    self.conditional_requests = conditional_requests;
+    self
+  }
+
+  /// Sends requests through `transport` instead of a `reqwest::Client`.
+  ///
+  /// The timeout, header, proxy, compression and conditional request options configure the
+  /// default transport and have no effect when a custom transport is used. Only the
+  /// asynchronous client supports custom transports.
+  pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+    self.transport = Some(Arc::new(transport));
+    self.http = None;
+    self
+  }
+
+  /// Sends requests through an existing `reqwest::Client`, sharing its connection pool, TLS
+  /// configuration and middleware with the rest of the application.
+  ///
+  /// As with [`EdboClientBuilder::transport`], the timeout, header, user agent, proxy and
+  /// compression options have no effect; configure them on `http` instead. The base URL,
+  /// retry, concurrency, cache and conditional request options still apply.
+  pub fn client(mut self, http: Client) -> Self {
+    self.http = Some(http);
+    self.transport = None;
+    self
+  }
+
+  pub(crate) fn retry_policy(&self) -> RetryPolicy {
+    RetryPolicy {
+      retries: self.retries,
+      base_delay: self.retry_base_delay.unwrap_or(DEFAULT_RETRY_BASE_DELAY),
+    }
+  }
+
+  pub(crate) fn disk_cache(&self) -> Option<DiskCache> {
+    let dir = self.cache_dir.clone()?;
+    Some(DiskCache::new(dir, self.cache_ttl.unwrap_or(DEFAULT_CACHE_TTL)))
+  }
+
+  #[cfg(not(target_arch = "wasm32"))]
+  pub(crate) fn rate_limiter(&self) -> Option<Arc<RateLimiter>> {
+    (self.rate_limit > 0).then(|| Arc::new(RateLimiter::new(self.rate_limit)))
+  }
+
+  pub(crate) fn memory_cache(&self) -> Option<Arc<MemoryCache>> {
+    (self.memory_cache_capacity > 0).then(|| Arc::new(MemoryCache::new(self.memory_cache_capacity)))
+  }
+
+  /// Converts the configured headers into a `HeaderMap`.
+  fn default_headers(&self) -> Result<HeaderMap, Error> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &self.headers {
+      let name = HeaderName::from_bytes(name.as_bytes())
+        .map_err(|_| Error::InvalidValue { kind: "header name", value: name.clone() })?;
+      let value = HeaderValue::from_str(value)
+        .map_err(|_| Error::InvalidValue { kind: "header value", value: value.clone() })?;
+      headers.append(name, value);
+    }
+    Ok(headers)
+  }
+
+  /// Returns the configured upper bound for registry IDs, [`DEFAULT_MAX_ID`] unless overridden.
+  pub(crate) fn resolved_max_id(&self) -> i32 {
+    self.max_id.unwrap_or(DEFAULT_MAX_ID)
+  }
+
+  /// Returns the configured concurrency limit, at least 1.
+  pub(crate) fn resolved_max_concurrency(&self) -> usize {
+    self.max_concurrency.unwrap_or(DEFAULT_MAX_CONCURRENCY).max(1)
+  }
+
+  /// Returns the configured base URL without a trailing slash.
+  pub(crate) fn resolved_base_url(&self) -> String {
+    self.base_url.as_deref().unwrap_or(BASE_URL).trim_end_matches('/').to_string()
+  }
+
+  /// Builds an asynchronous [`EdboClient`].
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if a configured header is invalid or the underlying `reqwest::Client`
+  /// cannot be constructed.
+  pub fn build(self) -> Result<EdboClient, Error> {
+    let transport: Arc<dyn HttpTransport> = match (&self.transport, &self.http) {
+      (Some(transport), _) => transport.clone(),
+      (None, Some(http)) => Arc::new(ReqwestTransport::new(http.clone()).conditional_requests(self.conditional_requests)),
+      (None, None) => Arc::new(ReqwestTransport::new(self.reqwest_client()?).conditional_requests(self.conditional_requests)),
+    };
+    let max_concurrency = self.resolved_max_concurrency();
+    Ok(EdboClient {
+      transport,
+      base_url: self.resolved_base_url(),
+      retry: self.retry_policy(),
+      max_concurrency,
+      max_id: self.resolved_max_id(),
+      limiter: Arc::new(Semaphore::new(max_concurrency)),
+      #[cfg(not(target_arch = "wasm32"))]
+      rate_limiter: self.rate_limiter(),
+      cache: self.disk_cache(),
+      memory: self.memory_cache(),
+      metrics: Arc::default(),
+      sort_results: self.sort_results,
+    })
+  }
+
+  fn reqwest_client(&self) -> Result<Client, Error> {
+    let mut http = Client::builder().default_headers(self.default_headers()?);
+    if let Some(user_agent) = &self.user_agent {
+      http = http.user_agent(user_agent);
+    }
+    // In the browser, compression, proxies and timeouts are controlled by the fetch API.
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+      #[cfg(feature = "compression")]
+      {
+        http = http.gzip(true).brotli(true);
+      }
+      if self.no_proxy {
+        http = http.no_proxy();
+      }
+      for proxy in &self.proxies {
+        http = http.proxy(proxy.clone());
+      }
+      if let Some(timeout) = self.timeout {
+        http = http.timeout(timeout);
+      }
+      if let Some(connect_timeout) = self.connect_timeout {
+        http = http.connect_timeout(connect_timeout);
+      }
+    }
+    Ok(http.build()?)
+  }
+
+  /// Builds a [`blocking::EdboClient`].
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if a configured header is invalid or the underlying
+  /// `reqwest::blocking::Client` cannot be constructed.
+  #[cfg(feature = "blocking")]
+  pub fn build_blocking(self) -> Result<blocking::EdboClient, Error> {
+    let mut http = reqwest::blocking::Client::builder().default_headers(self.default_headers()?);
+    #[cfg(feature = "compression")]
+    {
+      http = http.gzip(true).brotli(true);
+    }
+    if let Some(user_agent) = &self.user_agent {
+      http = http.user_agent(user_agent);
+    }
+    if self.no_proxy {
+      http = http.no_proxy();
+    }
+    for proxy in &self.proxies {
+      http = http.proxy(proxy.clone());
+    }
+    if let Some(timeout) = self.timeout {
+      http = http.timeout(timeout);
+    }
+    if let Some(connect_timeout) = self.connect_timeout {
+      http = http.connect_timeout(connect_timeout);
+    }
+    Ok(blocking::EdboClient::from_builder(http.build()?, &self))
+  }
+}
+
+/// An asynchronous client for the EDBO Registry API.
+///
+/// `EdboClient` owns a single [`HttpTransport`], by default a `reqwest::Client`, so every
+/// request made through it shares the same connection pool. Create one client and reuse it
+/// (clones share the transport) for all lookups instead of calling the free functions in a
+/// loop.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use libedbo::{EdboClient, SearchParams, Region, UniversityCategory};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = EdboClient::new();
+///     let params = SearchParams::new()
+///         .with_region(Region::KyivCity)
+///         .with_university_category(UniversityCategory::HigherEducationInstitutions);
+///
+///     let universities = client.search_universities(params).await?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct EdboClient {
+  transport: Arc<dyn HttpTransport>,
+  base_url: String,
+  retry: RetryPolicy,
+  max_concurrency: usize,
+  max_id: i32,
+  limiter: Arc<Semaphore>,
+  #[cfg(not(target_arch = "wasm32"))]
+  rate_limiter: Option<Arc<RateLimiter>>,
+  cache: Option<DiskCache>,
+  memory: Option<Arc<MemoryCache>>,
+  metrics: Arc<Metrics>,
+  sort_results: bool,
+}
+
+impl Default for EdboClient {
+  fn default() -> Self {
+    EdboClient::new()
+  }
+}
+
+impl EdboClient {
+  /// Creates a new client with a default `reqwest::Client` pointed at the official registry.
+  pub fn new() -> Self {
+    EdboClient::with_transport(ReqwestTransport::default())
+  }
+
+  /// Creates a client that sends its requests through `transport`, pointed at the official
+  /// registry. Use [`EdboClient::builder`] to combine a transport with other options.
+  pub fn with_transport(transport: impl HttpTransport + 'static) -> Self {
+    EdboClient {
+      transport: Arc::new(transport),
+      base_url: BASE_URL.to_string(),
+      retry: RetryPolicy::default(),
+      max_concurrency: DEFAULT_MAX_CONCURRENCY,
+      max_id: DEFAULT_MAX_ID,
+      limiter: Arc::new(Semaphore::new(DEFAULT_MAX_CONCURRENCY)),
+      #[cfg(not(target_arch = "wasm32"))]
+      rate_limiter: None,
+      cache: None,
+      memory: None,
+      metrics: Arc::default(),
+      sort_results: false,
+    }
+  }
+
+  /// Creates a client that sends its requests through an existing `reqwest::Client`, pointed
+  /// at the official registry.
+  ///
+  /// The client is used as-is, so its own timeouts, headers and TLS settings apply. Use
+  /// [`EdboClientBuilder::client`] to combine it with other options.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use std::time::Duration;
+  /// use libedbo::EdboClient;
+  ///
+  /// # fn main() -> Result<(), reqwest::Error> {
+  /// let http = reqwest::Client::builder().pool_max_idle_per_host(4).timeout(Duration::from_secs(10)).build()?;
+  /// let client = EdboClient::with_client(http);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn with_client(http: Client) -> Self {
+    EdboClient::with_transport(ReqwestTransport::new(http))
+  }
+
+  /// Returns a builder for configuring a client.
+  pub fn builder() -> EdboClientBuilder {
+    EdboClientBuilder::new()
+  }
+
+  /// Returns the base URL that endpoint paths are joined onto.
+  pub fn base_url(&self) -> &str {
+    &self.base_url
+  }
+
+  /// Returns the number of requests currently being sent through this client and its clones.
+  ///
+  /// This never exceeds [`EdboClientBuilder::max_concurrency`], and is useful as a gauge
+  /// in metrics.
+  pub fn in_flight(&self) -> usize {
+    self.max_concurrency - self.limiter.available_permits()
+  }
+
+  /// Returns the fraction of single-record lookups answered from the memory cache.
+  ///
+  /// Returns `None` if [`EdboClientBuilder::memory_cache_capacity`] was not set or no lookup
+  /// has been made yet. Lookups through clones of the client count too.
+  pub fn memory_cache_hit_ratio(&self) -> Option<f64> {
+    self.memory.as_ref()?.hit_ratio()
+  }
+
+  /// Returns a snapshot of the request, error and cache counters of this client and its clones.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, SearchParams};
+  ///
+  /// # async fn run() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::new();
+  /// client.search_university(SearchParams::new().with_id(41)).await?;
+  /// let metrics = client.metrics();
+  /// println!("{} requests, errors: {:?}", metrics.requests, metrics.errors);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn metrics(&self) -> EdboMetrics {
+    let memory = self.memory.as_deref();
+    self.metrics.snapshot(memory.map_or(0, MemoryCache::hits), memory.map_or(0, MemoryCache::misses))
+  }
+
+  /// Returns a client that shares this one's transport and options but neither reads from nor
+  /// writes to the response cache or the memory cache.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, SearchParams};
+  ///
+  /// # async fn run(client: &EdboClient) -> Result<(), libedbo::error::Error> {
+  /// let fresh = client.uncached().search_university(SearchParams::new().with_id(41)).await?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn uncached(&self) -> EdboClient {
+    EdboClient { cache: None, memory: None, ..self.clone() }
+  }
+
+  /// Returns the lazily-initialized client used by the free search functions.
+  ///
+  /// It is built once, on first use, with [`EdboClientBuilder::shared`], so every free
+  /// function call reuses the same connection pool.
+  pub(crate) fn shared() -> &'static EdboClient {
+    static SHARED: OnceLock<EdboClient> = OnceLock::new();
+    SHARED.get_or_init(|| EdboClientBuilder::shared().build().unwrap_or_else(|_| EdboClient::new()))
+  }
+
+  /// Sends a GET request through the transport, retrying transient failures according to the
+  /// client's retry policy, and deserializes the response.
+  ///
+  /// A fresh cached response is returned without a request, and a successful response is cached.
+  /// A `timeout` applies to each attempt on its own, in place of the client-wide timeout.
+  /// Errors are wrapped in `Error::Request` carrying `url`.
+  async fn get<T: DeserializeOwned>(&self, url: String, timeout: Option<Duration>) -> Result<T, Error> {
+    if let Some(cache) = &self.cache {
+      let cached = cache.get(&url);
+      self.metrics.cache_lookup(cached.is_some());
+      if let Some(value) = cached {
+        return serde_json::from_value(value).map_err(|err| Error::from(err).in_request(&url));
+      }
+    }
+    let mut attempt = 0;
+    loop {
+      match self.send_once(&url, timeout).await {
+        Ok(value) => {
+          let parsed = T::deserialize(&value).map_err(|err| Error::from(err).in_request(&url))?;
+          if let Some(cache) = &self.cache {
+            cache.put(&url, &value);
+          }
+          return Ok(parsed);
+        }
+        Err(err) => match self.retry.delay(attempt, &err) {
+          Some(delay) => retry::sleep(delay).await,
+          None => return Err(err.in_request(&url)),
+        },
+      }
+      attempt += 1;
+    }
+  }
+
+  /// Sends a single request for `url` through the transport, without retries or caching.
+  ///
+  /// The request waits for the rate limit and a concurrency permit, and is counted in the
+  /// client's metrics.
+  async fn send_once(&self, url: &str, timeout: Option<Duration>) -> Result<Value, Error> {
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(rate_limiter) = &self.rate_limiter {
+      retry::sleep(rate_limiter.reserve()).await;
+    }
+    self.metrics.request();
+    let response = {
+      let _permit = self.limiter.acquire().await;
+      match timeout {
+        Some(timeout) => self.transport.get_json_with_timeout(url, timeout).await,
+        None => self.transport.get_json(url).await,
+      }
+    };
+    self.metrics.record(&response);
+    response
+  }
+
+  /// Fetches the single record that `operation` names by ID, going through the memory cache.
+  async fn get_record<T>(&self, operation: Operation, param: &SearchParams, kind: &'static str, timeout: Option<Duration>) -> Result<T, Error>
+  where
+    T: DeserializeOwned + Clone + Send + Sync + 'static,
+  {
+    let url = param.url_for(operation, &self.base_url)?;
+    check_id(param.id, self.max_id)?;
+    let cached = self.memory.as_ref().zip(param.id);
+    if let Some(record) = cached.and_then(|(memory, id)| memory.get(operation.endpoint(), id)) {
+      return Ok(record);
+    }
+    let record: T = self.get(url, timeout).await.map_err(|err| err.for_resource(kind, param.id))?;
+    if let Some((memory, id)) = cached {
+      memory.put(operation.endpoint(), id, record.clone());
+    }
+    Ok(record)
+  }
+
+  /// Checks that the registry is reachable and answering with JSON, before starting a long job.
+  ///
+  /// The probe is a fixed listing: the higher education institutions of Chernivtsi Oblast,
+  /// requested once, whose response must deserialize as a university listing. The registry
+  /// has no lightweight status endpoint, so each call downloads and parses that whole
+  /// regional listing; call it once before a job rather than in a polling loop.
+  ///
+  /// Unlike the search methods, the check never answers from the disk cache and is not
+  /// retried, so a failure is reported at once. It does go through the rate limit, and a
+  /// successful check leaves a warm connection in the pool for the requests that follow.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::EdboClient;
+  ///
+  /// # async fn run() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::new();
+  /// client.health_check().await?;
+  /// let sweep = client.search_all_regions(libedbo::UniversityCategory::HigherEducationInstitutions).await;
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the request's error wrapped in `Error::Request`: a transport error if the
+  /// registry is unreachable, `Error::ApiError` or `Error::RateLimited` for an unsuccessful
+  /// status, `Error::UnexpectedContentType` for a non-JSON body such as a maintenance page, and
+  /// `Error::ParsingError` for JSON that is not a listing.
+  pub async fn health_check(&self) -> Result<(), Error> {
+    let url = SearchParams::universities(Region::ChernivtsiOblast).url_for(Operation::Universities, &self.base_url)?;
+    let value = self.send_once(&url, None).await.map_err(|err| err.in_request(&url))?;
+    OneOrMany::<UniversityBrief>::deserialize(&value).map_err(|err| Error::from(err).in_request(&url))?;
+    Ok(())
+  }
+
+  /// Searches for universities based on provided parameters.
+  ///
+  /// The registry returns the whole listing at once, with no paging or total count; see
+  /// [`EdboClient::search_universities_page`] to process it in pages.
+  ///
+  /// # Arguments
+  ///
+  /// * `param` - Search parameters including region, university category and an optional name filter
+  ///
+  /// # Returns
+  ///
+  /// * `Ok(Vec<UniversityBrief>)` - List of universities matching the search criteria
+  /// * `Err(Error)` - Search request failed
+  pub async fn search_universities(&self, param: SearchParams) -> Result<Vec<UniversityBrief>, Error> {
+    let url = param.url_for(Operation::Universities, &self.base_url)?;
+    let OneOrMany(mut universities) = self.get(url, None).await?;
+    universities.retain(|uni| param.matches_university(uni));
+    if self.sort_results {
+      dedup_universities(&mut universities);
+      sort_universities(&mut universities);
+    }
+    Ok(universities)
+  }
+
+  /// Returns page `page` (zero-based) of `page_size` universities, together with the total
+  /// number of universities matching `param`.
+  ///
+  /// The registry has no paging, so every call downloads the full listing and cuts the page
+  /// out of it. Enable the [disk cache](EdboClientBuilder::cache_dir) to avoid refetching the
+  /// listing for each page.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, Region, SearchParams, UniversityCategory};
+  ///
+  /// #[tokio::main]
+  /// async fn main() -> Result<(), libedbo::error::Error> {
+  ///     let client = EdboClient::new();
+  ///     let params = SearchParams::new()
+  ///         .with_region(Region::KyivCity)
+  ///         .with_university_category(UniversityCategory::HigherEducationInstitutions);
+  ///     let page = client.search_universities_page(params, 0, 20).await?;
+  ///     println!("showing {} of {}", page.items.len(), page.total);
+  ///     Ok(())
+  /// }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidValue` if `page_size` is 0, and otherwise the same errors as
+  /// [`EdboClient::search_universities`].
+  pub async fn search_universities_page(&self, param: SearchParams, page: usize, page_size: usize) -> Result<Page<UniversityBrief>, Error> {
+    Page::from_records(self.search_universities(param).await?, page, page_size)
+  }
+
+  /// Searches for universities like [`EdboClient::search_universities`], but treats an empty
+  /// result as an error.
+  ///
+  /// The registry answers a search that matches nothing with an empty array, just as it does
+  /// for a valid region and category that have no universities. This lets a caller such as a
+  /// search form report "no matches" without checking the length itself.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, Region, SearchParams, UniversityCategory};
+  /// use libedbo::error::Error;
+  ///
+  /// # async fn run(client: &EdboClient) -> Result<(), Error> {
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::KyivCity)
+  ///     .with_university_category(UniversityCategory::HigherEducationInstitutions)
+  ///     .with_name("Сорбонна");
+  /// match client.search_universities_non_empty(params).await {
+  ///     Ok(universities) => println!("{} matches", universities.len()),
+  ///     Err(Error::EmptyResult) => println!("no matches"),
+  ///     Err(err) => return Err(err),
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::EmptyResult` if no university matches, and otherwise the same errors as
+  /// [`EdboClient::search_universities`].
+  pub async fn search_universities_non_empty(&self, param: SearchParams) -> Result<Vec<UniversityBrief>, Error> {
+    non_empty(self.search_universities(param).await?)
+  }
+
+  /// Retrieves detailed information about a specific university.
+  ///
+  /// # Arguments
+  ///
+  /// * `param` - Search parameters containing the university ID
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if:
+  /// - The ID is missing or less than 1
+  /// - The API request fails
+  /// - The university is not found, as `Error::NotFound` inside `Error::Request`
+  pub async fn search_university(&self, param: SearchParams) -> Result<University, Error> {
+    self.get_record(Operation::University, &param, "university", None).await
+  }
+
+  /// Retrieves detailed information about a specific university, with a timeout for this call
+  /// only.
+  ///
+  /// Full university records can be large, so a fetch may need longer than the client-wide
+  /// [`EdboClientBuilder::timeout`] that keeps listings snappy. `timeout` takes precedence
+  /// over it for every attempt of this call, including retries;
+  /// [`EdboClientBuilder::connect_timeout`] still applies. With a custom [`HttpTransport`] the
+  /// timeout is passed to [`HttpTransport::get_json_with_timeout`], which ignores it unless the
+  /// transport overrides it.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use std::time::Duration;
+  /// use libedbo::{EdboClient, SearchParams};
+  ///
+  /// # async fn run() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::builder().timeout(Duration::from_secs(5)).build()?;
+  /// let university = client.search_university_with_timeout(SearchParams::new().with_id(41), Duration::from_secs(60)).await?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`EdboClient::search_university`]. A request that runs out of
+  /// time fails with `Error::Timeout` and is retried like any other timeout.
+  pub async fn search_university_with_timeout(&self, param: SearchParams, timeout: Duration) -> Result<University, Error> {
+    self.get_record(Operation::University, &param, "university", Some(timeout)).await
+  }
+
+  /// Retrieves the listing-level summary of a university by ID.
+  ///
+  /// The registry has no endpoint that returns a single summary: the listing at
+  /// `/api/universities` can only be filtered by region and category. This method therefore
+  /// fetches the full record, exactly like [`EdboClient::search_university`], and keeps its
+  /// summary fields, so it does not save bandwidth. What it saves is memory and handling: the
+  /// branches, licenses and educators are dropped as soon as they arrive. Combine it with
+  /// [`EdboClientBuilder::memory_cache_capacity`] or [`EdboClientBuilder::cache_dir`] to avoid
+  /// repeating the download.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::EdboClient;
+  ///
+  /// # async fn run(client: &EdboClient) -> Result<(), libedbo::error::Error> {
+  /// let brief = client.university_brief(41).await?;
+  /// println!("{} — {}", brief.university_name, brief.university_phone);
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`EdboClient::search_university`].
+  pub async fn university_brief(&self, id: impl Into<i32>) -> Result<UniversityBrief, Error> {
+    self.search_university(SearchParams::new().with_id(id)).await.map(UniversityBrief::from)
+  }
+
+  /// Retrieves a university's record as the registry's JSON, without deserializing it into
+  /// [`University`].
+  ///
+  /// Every field the registry sends is kept, including ones the typed model does not have
+  /// yet. The request goes through the client's retries, rate limit and disk cache like any
+  /// other; the memory cache, which holds typed records, is not used.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::EdboClient;
+  ///
+  /// # async fn run(client: &EdboClient) -> Result<(), libedbo::error::Error> {
+  /// let json = client.university_json(41).await?;
+  /// println!("{}", json["university_name"]);
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`EdboClient::search_university`], except that a body which
+  /// is JSON but does not match the model is returned rather than rejected.
+  pub async fn university_json(&self, id: impl Into<i32>) -> Result<Value, Error> {
+    let param = SearchParams::new().with_id(id);
+    let url = param.url_for(Operation::University, &self.base_url)?;
+    check_id(param.id, self.max_id)?;
+    self.get(url, None).await.map_err(|err| err.for_resource("university", param.id))
+  }
+
+  /// Retrieves detailed information about a specific university, giving up when `token` is cancelled.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::Cancelled` if the token fires before the response arrives, and otherwise the
+  /// same errors as [`EdboClient::search_university`].
+  pub async fn search_university_with_cancel(&self, param: SearchParams, token: &CancellationToken) -> Result<University, Error> {
+    tokio::select! {
+      biased;
+      _ = token.cancelled() => Err(Error::Cancelled),
+      result = self.search_university(param) => result,
+    }
+  }
+
+  /// Searches for secondary education institutions based on provided parameters.
+  ///
+  /// Like the university listing, the registry returns every matching institution at once,
+  /// with no paging or total count. A name set with [`SearchParams::with_name`] is matched
+  /// against `institution_name` and `short_name` in the crate, after the listing arrives.
+  ///
+  /// # Arguments
+  ///
+  /// * `param` - Search parameters including region and institution category
+  ///
+  /// # Returns
+  ///
+  /// * `Ok(Vec<Institution>)` - List of institutions matching the search criteria
+  /// * `Err(Error)` - Search request failed
+  pub async fn search_institutions(&self, param: SearchParams) -> Result<Vec<Institution>, Error> {
+    let url = param.url_for(Operation::Institutions, &self.base_url)?;
+    let OneOrMany(mut institutions) = self.get(url, None).await?;
+    institutions.retain(|institution| param.matches_institution(institution));
+    Ok(institutions)
+  }
+
+  /// Retrieves detailed information about a specific school.
+  ///
+  /// # Arguments
+  ///
+  /// * `param` - Search parameters containing the school ID
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if:
+  /// - The ID is missing or less than 1
+  /// - The API request fails
+  /// - The school is not found, as `Error::NotFound` inside `Error::Request`
+  pub async fn search_school(&self, param: SearchParams) -> Result<Institution, Error> {
+    self.get_record(Operation::School, &param, "school", None).await
+  }
+
+  /// Retrieves detailed information about a specific school, with a timeout for this call only.
+  ///
+  /// The timeout takes precedence over the client-wide one, as for
+  /// [`EdboClient::search_university_with_timeout`].
+  pub async fn search_school_with_timeout(&self, param: SearchParams, timeout: Duration) -> Result<Institution, Error> {
+    self.get_record(Operation::School, &param, "school", Some(timeout)).await
+  }
+
+  /// Retrieves a school's record as the registry's JSON, without deserializing it into
+  /// [`Institution`]. See [`EdboClient::university_json`].
+  pub async fn school_json(&self, id: impl Into<i32>) -> Result<Value, Error> {
+    let param = SearchParams::new().with_id(id);
+    let url = param.url_for(Operation::School, &self.base_url)?;
+    check_id(param.id, self.max_id)?;
+    self.get(url, None).await.map_err(|err| err.for_resource("school", param.id))
+  }
+
+  /// Lists universities like [`EdboClient::search_universities`], bundling the result with
+  /// `param` and the time the search returned.
+  ///
+  /// Not available on wasm32, where [`SearchResult`] cannot read the clock.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use std::time::Duration;
+  /// use libedbo::{EdboClient, Region, SearchParams, SearchResult, UniversityBrief, UniversityCategory};
+  ///
+  /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+  /// let client = EdboClient::new();
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::KyivCity)
+  ///     .with_university_category(UniversityCategory::HigherEducationInstitutions);
+  /// let result = client.search_universities_with_meta(params).await?;
+  /// std::fs::write("kyiv.json", serde_json::to_vec(&result)?)?;
+  ///
+  /// // Later, refresh the stored listing once it is a day old.
+  /// let mut cached: SearchResult<Vec<UniversityBrief>> = serde_json::from_slice(&std::fs::read("kyiv.json")?)?;
+  /// if cached.is_older_than(Duration::from_secs(24 * 60 * 60)) {
+  ///     cached = client.search_universities_with_meta(cached.params).await?;
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`EdboClient::search_universities`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub async fn search_universities_with_meta(&self, param: SearchParams) -> Result<SearchResult<Vec<UniversityBrief>>, Error> {
+    let data = self.search_universities(param.clone()).await?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Fetches a university like [`EdboClient::search_university`], bundling it with `param`
+  /// and the time the search returned. See [`EdboClient::search_universities_with_meta`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub async fn search_university_with_meta(&self, param: SearchParams) -> Result<SearchResult<University>, Error> {
+    let data = self.search_university(param.clone()).await?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Lists institutions like [`EdboClient::search_institutions`], bundling the result with
+  /// `param` and the time the search returned. See [`EdboClient::search_universities_with_meta`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub async fn search_institutions_with_meta(&self, param: SearchParams) -> Result<SearchResult<Vec<Institution>>, Error> {
+    let data = self.search_institutions(param.clone()).await?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Fetches a school like [`EdboClient::search_school`], bundling it with `param` and the
+  /// time the search returned. See [`EdboClient::search_universities_with_meta`].
+  #[cfg(not(target_arch = "wasm32"))]
+  pub async fn search_school_with_meta(&self, param: SearchParams) -> Result<SearchResult<Institution>, Error> {
+    let data = self.search_school(param.clone()).await?;
+    Ok(SearchResult::new(param, data))
+  }
+
+  /// Retrieves detailed information about several universities concurrently.
+  ///
+  /// At most [`EdboClientBuilder::max_concurrency`] requests are in flight at once. The
+  /// results are in the same order as `ids`, and a failed lookup does not affect the others.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::EdboClient;
+  ///
+  /// #[tokio::main]
+  /// async fn main() {
+  ///     let client = EdboClient::new();
+  ///     for result in client.search_universities_by_ids(&[41, 79, 174]).await {
+  ///         match result {
+  ///             Ok(uni) => println!("{}", uni.university_name),
+  ///             Err(err) => eprintln!("{err}"),
+  ///         }
+  ///     }
+  /// }
+  /// ```
+  pub async fn search_universities_by_ids(&self, ids: &[i32]) -> Vec<Result<University, Error>> {
+    self.fan_out(ids, |&id| self.search_university(SearchParams::new().with_id(id))).await
+  }
+
+  /// Retrieves detailed information about several schools concurrently.
+  ///
+  /// At most [`EdboClientBuilder::max_concurrency`] requests are in flight at once. The
+  /// results are in the same order as `ids`, and a failed lookup does not affect the others.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::EdboClient;
+  ///
+  /// #[tokio::main]
+  /// async fn main() {
+  ///     let client = EdboClient::new();
+  ///     for result in client.search_schools_by_ids(&[12, 345, 6789]).await {
+  ///         match result {
+  ///             Ok(school) => println!("{}", school.institution_name),
+  ///             Err(err) => eprintln!("{err}"),
+  ///         }
+  ///     }
+  /// }
+  /// ```
+  pub async fn search_schools_by_ids(&self, ids: &[i32]) -> Vec<Result<Institution, Error>> {
+    self.fan_out(ids, |&id| self.search_school(SearchParams::new().with_id(id))).await
+  }
+
+  /// Streams detailed information about the universities in `ids`, pulling IDs as needed.
+  ///
+  /// Unlike [`EdboClient::search_universities_by_ids`], the IDs are not collected up front:
+  /// the stream takes the next ID only when a request slot frees up, so memory stays bounded
+  /// by [`EdboClientBuilder::max_concurrency`] however long `ids` is. Each request also goes
+  /// through the client's [`rate_limit`](EdboClientBuilder::rate_limit). Items are yielded in
+  /// the order of `ids`, each paired with its ID, and a failed lookup does not end the stream.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use futures::StreamExt;
+  /// use libedbo::EdboClient;
+  ///
+  /// #[tokio::main]
+  /// async fn main() {
+  ///     let client = EdboClient::new();
+  ///     let mut universities = std::pin::pin!(client.enrich_universities(1..=50_000));
+  ///     while let Some((id, result)) = universities.next().await {
+  ///         match result {
+  ///             Ok(uni) => println!("{id}: {}", uni.university_name),
+  ///             Err(err) => eprintln!("{id}: {err}"),
+  ///         }
+  ///     }
+  /// }
+  /// ```
+  pub fn enrich_universities<'a, I>(&'a self, ids: I) -> impl Stream<Item = (i32, Result<University, Error>)> + 'a
+  where
+    I: IntoIterator<Item = i32>,
+    I::IntoIter: 'a,
+  {
+    stream::iter(ids)
+      .map(move |id| async move { (id, self.search_university(SearchParams::by_id(id)).await) })
+      .buffered(self.max_concurrency)
+  }
+
+  /// Runs `request` for every item with at most `max_concurrency` requests in flight,
+  /// returning the results in input order.
+  async fn fan_out<I, F, Fut, T>(&self, items: I, request: F) -> Vec<T>
+  where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> Fut,
+    Fut: Future<Output = T>,
+  {
+    stream::iter(items).map(request).buffered(self.max_concurrency).collect().await
+  }
+
+  /// Lists the universities of `category` in every region of Ukraine.
+  ///
+  /// One request is sent per region, with at most [`EdboClientBuilder::max_concurrency`] in
+  /// flight at once. Results are returned in region order, and a university listed under
+  /// several regions appears only once, at its first occurrence. With
+  /// [`EdboClientBuilder::sort_results`] the combined list is sorted by name instead.
+  ///
+  /// A region that fails is recorded in [`SweepResult::failures`] and does not discard the
+  /// responses of the other regions. Use [`SweepResult::into_result`] to treat the sweep as
+  /// failed only when no region could be listed.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, UniversityCategory};
+  ///
+  /// # async fn run() {
+  /// let client = EdboClient::new();
+  /// let sweep = client.search_all_regions(UniversityCategory::HigherEducationInstitutions).await;
+  /// for (region, err) in &sweep.failures {
+  ///     eprintln!("{region:?} failed: {err}");
+  /// }
+  /// println!("{} universities", sweep.universities.len());
+  /// # }
+  /// ```
+  pub async fn search_all_regions(&self, category: UniversityCategory) -> SweepResult {
+    let responses = self
+      .fan_out(Region::ALL, |region| {
+        self.search_universities(SearchParams::new().with_region(region).with_university_category(category))
+      })
+      .await;
+    SweepResult::collect(responses, self.sort_results)
+  }
+
+  /// Streams the universities of `category` in every region of Ukraine as they arrive.
+  ///
+  /// Regions are requested in region order, with at most [`EdboClientBuilder::max_concurrency`]
+  /// in flight, and the stream only runs ahead of its consumer by that many regions. A
+  /// university listed under several regions is yielded once. A region that fails yields a
+  /// single `Err` item and the sweep carries on with the next region. Dropping the stream stops
+  /// the sweep. Unlike [`EdboClient::search_all_regions`], results are never sorted.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use futures::StreamExt;
+  /// use libedbo::{EdboClient, UniversityCategory};
+  ///
+  /// #[tokio::main]
+  /// async fn main() {
+  ///     let client = EdboClient::new();
+  ///     let mut universities = std::pin::pin!(client.stream_universities(UniversityCategory::HigherEducationInstitutions));
+  ///     while let Some(result) = universities.next().await {
+  ///         match result {
+  ///             Ok(uni) => println!("{}", uni.university_name),
+  ///             Err(err) => eprintln!("{err}"),
+  ///         }
+  ///     }
+  /// }
+  /// ```
+  pub fn stream_universities(&self, category: UniversityCategory) -> impl Stream<Item = Result<UniversityBrief, Error>> + '_ {
+    let mut seen = HashSet::new();
+    stream::iter(Region::ALL)
+      .map(move |region| self.search_universities(SearchParams::new().with_region(region).with_university_category(category)))
+      .buffered(self.max_concurrency)
+      .flat_map(|response| {
+        let items: Vec<_> = match response {
+          Ok(universities) => universities.into_iter().map(Ok).collect(),
+          Err(err) => vec![Err(err)],
+        };
+        stream::iter(items)
+      })
+      .filter(move |result| {
+        future::ready(match result {
+          Ok(university) => seen.insert(university.university_id),
+          Err(_) => true,
+        })
+      })
+  }
+
+  /// Lists the institutions of every [`InstitutionCategory`] in `region`.
+  ///
+  /// One request is sent per category in [`InstitutionCategory::ALL`], with at most
+  /// [`EdboClientBuilder::max_concurrency`] in flight at once. The lists are concatenated in
+  /// category order, and an institution listed under several categories is kept only at its
+  /// first occurrence.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, Region};
+  ///
+  /// # async fn run() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::new();
+  /// let institutions = client.search_all_institution_types(Region::LvivOblast).await?;
+  /// println!("{} institutions in Lviv Oblast", institutions.len());
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the error of the first category, in category order, whose request failed.
+  pub async fn search_all_institution_types(&self, region: Region) -> Result<Vec<Institution>, Error> {
+    let responses = self
+      .fan_out(InstitutionCategory::ALL, |category| {
+        self.search_institutions(SearchParams::new().with_region(region).with_institution_category(category))
+      })
+      .await;
+    let mut institutions = Vec::new();
+    for response in responses {
+      institutions.extend(response?);
+    }
+    dedup_institutions(&mut institutions);
+    Ok(institutions)
+  }
+
+  /// Fetches the parent of `university`, if it is a branch of another university.
+  ///
+  /// # Returns
+  ///
+  /// * `Ok(Some(University))` - The parent university
+  /// * `Ok(None)` - The university has no parent
+  /// * `Err(Error)` - The request failed
+  pub async fn fetch_parent(&self, university: &University) -> Result<Option<University>, Error> {
+    match university.parent_id() {
+      Some(id) => self.search_university(SearchParams::new().with_id(id)).await.map(Some),
+      None => Ok(None),
+    }
+  }
+
+  /// Fetches the institution `institution` belongs to, if it has a parent.
+  ///
+  /// Calling this repeatedly walks a school network up to its umbrella organization.
+  ///
+  /// # Returns
+  ///
+  /// * `Ok(Some(Institution))` - The parent institution
+  /// * `Ok(None)` - The institution has no parent
+  /// * `Err(Error)` - The request failed
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{EdboClient, SearchParams};
+  ///
+  /// # async fn run() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::new();
+  /// let mut institution = client.search_school(SearchParams::new().with_id(137452)).await?;
+  /// while let Some(parent) = client.fetch_parent_institution(&institution).await? {
+  ///     println!("{} belongs to {}", institution.institution_name, parent.institution_name);
+  ///     institution = parent;
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub async fn fetch_parent_institution(&self, institution: &Institution) -> Result<Option<Institution>, Error> {
+    match institution.parent_id() {
+      Some(id) => self.search_school(SearchParams::new().with_id(id)).await.map(Some),
+      None => Ok(None),
+    }
+  }
+
+  /// Fetches the full records of every branch of `university` concurrently.
+  ///
+  /// At most [`EdboClientBuilder::max_concurrency`] requests are in flight at once. The
+  /// results are in the same order as `university.branches`.
+  pub async fn fetch_branches(&self, university: &University) -> Vec<Result<University, Error>> {
+    self.fan_out(&university.branches, |branch| self.search_university(SearchParams::new().with_id(branch.id()))).await
+  }
+
+  /// Fetches the university with ID `id` and the full records of all its branches.
+  ///
+  /// The branches are fetched concurrently, like [`EdboClient::fetch_branches`], in the order
+  /// the university lists them. A branch listed more than once is fetched once, and the
+  /// university itself is not fetched again if it appears among its own branches.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::EdboClient;
+  ///
+  /// # async fn run() -> Result<(), libedbo::error::Error> {
+  /// let client = EdboClient::new();
+  /// let tree = client.fetch_university_tree(41).await?;
+  /// println!("{}", tree.root.university_name);
+  /// for branch in &tree.branches {
+  ///     println!("  └ {}", branch.university_name);
+  /// }
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the error of the root's fetch, or of the first branch in list order that could
+  /// not be fetched.
+  pub async fn fetch_university_tree(&self, id: impl Into<i32>) -> Result<UniversityTree, Error> {
+    let root = self.search_university(SearchParams::new().with_id(id)).await?;
+    let branch_ids = UniversityTree::branch_ids(&root);
+    let branches = self.fan_out(branch_ids, |id| self.search_university(SearchParams::new().with_id(id))).await;
+    Ok(UniversityTree { root, branches: branches.into_iter().collect::<Result<_, _>>()? })
+  }
+}
+
+/// Rejects an ID above `max_id` with `Error::InvalidId`.
+pub(crate) fn check_id(id: Option<i32>, max_id: i32) -> Result<(), Error> {
+  match id {
+    Some(id) if id > max_id => Err(Error::InvalidId { id, max: max_id }),
+    _ => Ok(()),
+  }
+}
+
+/// Returns `records`, or `Error::EmptyResult` if there are none.
+pub(crate) fn non_empty<T>(records: Vec<T>) -> Result<Vec<T>, Error> {
+  if records.is_empty() {
+    Err(Error::EmptyResult)
+  } else {
+    Ok(records)
+  }
+}
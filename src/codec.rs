@@ -0,0 +1,91 @@
+//! Decoding of registry payloads without a network round trip.
+//!
+//! The client decodes every response with the functions in this module, so payloads that
+//! arrive through another channel, such as a message queue or a file, produce the same typed
+//! models and the same errors as a live request.
+
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+
+use crate::error::{self, Error};
+
+/// Deserializes a registry response body into `T`.
+///
+/// `T` is any of the crate's models, or a `Vec` of them for listing responses. A body that
+/// does not start with a JSON object or array, such as an HTML maintenance page, is rejected
+/// as `Error::UnexpectedContentType` before deserialization is attempted. A leading byte
+/// order mark and whitespace are skipped.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::codec::parse_response;
+/// use libedbo::error::Error;
+/// use libedbo::UniversityBrief;
+///
+/// let universities: Vec<UniversityBrief> = parse_response(b"[]")?;
+/// assert!(universities.is_empty());
+///
+/// let err = parse_response::<Vec<UniversityBrief>>(b"<html>maintenance</html>").unwrap_err();
+/// assert!(matches!(err, Error::UnexpectedContentType { .. }));
+///
+/// // A legacy page in Windows-1251 is still reported, with its invalid bytes replaced.
+/// let err = parse_response::<Vec<UniversityBrief>>(b"<html>\xcf\xee\xec\xe8\xeb\xea\xe0</html>").unwrap_err();
+/// let Error::UnexpectedContentType { snippet, .. } = err else { unreachable!() };
+/// assert!(snippet.starts_with("[body is not valid UTF-8, shown lossily] <html>\u{fffd}"));
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns `Error::UnexpectedContentType` if the body is not JSON, and `Error::ParsingError`
+/// if it does not match `T`.
+pub fn parse_response<T: DeserializeOwned>(body: &[u8]) -> Result<T, Error> {
+  decode_json(String::new(), body)
+}
+
+/// Deserializes a successful response body, first checking that it actually is JSON.
+///
+/// The registry occasionally answers with an HTML maintenance page and a 200 status. Such a
+/// body is reported as `Error::UnexpectedContentType` rather than as a parsing error, so that
+/// an outage is distinguishable from a schema change.
+pub(crate) fn decode_json<T: DeserializeOwned>(content_type: String, body: &[u8]) -> Result<T, Error> {
+  let body_start = body.strip_prefix("\u{feff}".as_bytes()).unwrap_or(body).trim_ascii_start();
+  let looks_like_json = matches!(body_start.first(), Some(b'{' | b'['));
+  if !looks_like_json || content_type.to_ascii_lowercase().contains("html") {
+    return Err(Error::UnexpectedContentType { content_type, snippet: error::body_snippet(body) });
+  }
+  Ok(serde_json::from_slice(body_start)?)
+}
+
+/// A list that the registry may send either as a JSON array or, for a single element, as the
+/// bare element.
+///
+/// The listing endpoints occasionally return one object where a one-element array is expected.
+/// The client reads listings through this type, and it can be given to [`parse_response`] as
+/// well. A malformed element is reported with the error for that element.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::codec::OneOrMany;
+///
+/// let OneOrMany(many) = serde_json::from_str::<OneOrMany<u32>>("[1, 2]").unwrap();
+/// assert_eq!(many, [1, 2]);
+/// let OneOrMany(one) = serde_json::from_str::<OneOrMany<u32>>("3").unwrap();
+/// assert_eq!(one, [3]);
+/// assert!(serde_json::from_str::<OneOrMany<u32>>(r#"["x"]"#).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OneOrMany<T>(pub Vec<T>);
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for OneOrMany<T> {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let items = match Value::deserialize(deserializer)? {
+      items @ Value::Array(_) => Vec::deserialize(items),
+      item => T::deserialize(item).map(|item| vec![item]),
+    };
+    items.map(OneOrMany).map_err(D::Error::custom)
+  }
+}
@@ -0,0 +1,67 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::model::{Institution, UniversityBrief};
+
+/// The Ukrainian alphabet, in collation order.
+const UKRAINIAN_ALPHABET: &str = "абвгґдеєжзиіїйклмнопрстуфхцчшщьюя";
+
+/// Compares two strings in Ukrainian alphabetical order.
+///
+/// Letters are compared case-insensitively by their position in the Ukrainian alphabet, so
+/// `Ґ` sorts after `Г` and `Є`, `І`, `Ї` sort after `Е` and `И`, unlike in plain code point
+/// order. Apostrophes and stress marks are ignored. Punctuation and spaces sort first, then
+/// digits, then Cyrillic letters, then Latin letters. Strings that differ only in case fall
+/// back to a code point comparison, so the order is total and deterministic.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::ukrainian_cmp;
+///
+/// let mut names = vec!["Їжак", "Ґанок", "Яблуко", "Гора", "Інститут", "Київ"];
+/// names.sort_by(|a, b| ukrainian_cmp(a, b));
+/// assert_eq!(names, ["Гора", "Ґанок", "Інститут", "Їжак", "Київ", "Яблуко"]);
+/// ```
+pub fn ukrainian_cmp(a: &str, b: &str) -> Ordering {
+  collation_keys(a).cmp(collation_keys(b)).then_with(|| a.cmp(b))
+}
+
+/// Sorts `universities` by name in Ukrainian alphabetical order, breaking ties by ID.
+pub(crate) fn sort_universities(universities: &mut [UniversityBrief]) {
+  universities.sort_by(|a, b| {
+    ukrainian_cmp(&a.university_name, &b.university_name).then_with(|| a.university_id.cmp(&b.university_id))
+  });
+}
+
+/// Removes universities whose ID already appeared earlier in the list.
+pub(crate) fn dedup_universities(universities: &mut Vec<UniversityBrief>) {
+  let mut seen = HashSet::new();
+  universities.retain(|university| seen.insert(university.university_id));
+}
+
+/// Removes institutions whose ID already appeared earlier in the list.
+pub(crate) fn dedup_institutions(institutions: &mut Vec<Institution>) {
+  let mut seen = HashSet::new();
+  institutions.retain(|institution| seen.insert(institution.institution_id));
+}
+
+/// Maps each significant character of `s` to a `(class, rank)` sort key.
+fn collation_keys(s: &str) -> impl Iterator<Item = (u8, u32)> + '_ {
+  s.chars()
+    .filter(|c| !matches!(c, '\'' | '’' | 'ʼ' | '`' | '\u{0301}'))
+    .flat_map(char::to_lowercase)
+    .map(|c| {
+      if let Some(rank) = UKRAINIAN_ALPHABET.chars().position(|letter| letter == c) {
+        (2, rank as u32)
+      } else if c.is_numeric() {
+        (1, c as u32)
+      } else if c.is_alphabetic() && ('\u{0400}'..='\u{04ff}').contains(&c) {
+        (2, UKRAINIAN_ALPHABET.len() as u32 + c as u32)
+      } else if c.is_alphabetic() {
+        (3, c as u32)
+      } else {
+        (0, c as u32)
+      }
+    })
+}
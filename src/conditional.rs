@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use reqwest::header::{HeaderMap, HeaderValue, ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use serde_json::Value;
+
+/// The last response seen for a URL, together with the validators it was sent with.
+#[derive(Debug)]
+struct Entry {
+  etag: Option<HeaderValue>,
+  last_modified: Option<HeaderValue>,
+  body: Value,
+}
+
+/// Remembers the `ETag` and `Last-Modified` validators of responses, keyed by URL, so that
+/// repeated requests can be made conditional and answered from memory on a `304 Not Modified`.
+///
+/// Only responses that carry at least one validator are kept. If the server sends neither,
+/// nothing is stored and every request goes out unconditionally.
+#[derive(Debug, Default)]
+pub(crate) struct Validators {
+  entries: Mutex<HashMap<String, Entry>>,
+}
+
+impl Validators {
+  /// Returns the `If-None-Match` and `If-Modified-Since` headers to send with a request for `url`.
+  pub(crate) fn conditional_headers(&self, url: &str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    if let Some(entry) = self.lock().get(url) {
+      if let Some(etag) = &entry.etag {
+        headers.insert(IF_NONE_MATCH, etag.clone());
+      }
+      if let Some(last_modified) = &entry.last_modified {
+        headers.insert(IF_MODIFIED_SINCE, last_modified.clone());
+      }
+    }
+    headers
+  }
+
+  /// Returns the body stored for `url`, to answer a `304 Not Modified`.
+  pub(crate) fn body(&self, url: &str) -> Option<Value> {
+    self.lock().get(url).map(|entry| entry.body.clone())
+  }
+
+  /// Records the validators in `headers` and the body of a successful response for `url`.
+  pub(crate) fn store(&self, url: &str, headers: &HeaderMap, body: &Value) {
+    let etag = headers.get(ETAG).cloned();
+    let last_modified = headers.get(LAST_MODIFIED).cloned();
+    let mut entries = self.lock();
+    if etag.is_none() && last_modified.is_none() {
+      entries.remove(url);
+    } else {
+      entries.insert(url.to_string(), Entry { etag, last_modified, body: body.clone() });
+    }
+  }
+
+  fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+    self.entries.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
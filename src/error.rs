@@ -1,11 +1,277 @@
-#[derive(Debug, thiserror::Error)]
+use std::sync::Arc;
+use std::time::Duration;
+use crate::search::Operation;
+
+/// Maximum number of characters of a response body shown in an error message.
+const MAX_BODY_DISPLAY_CHARS: usize = 200;
+
+/// Shortens a response body for display, marking where it was cut.
+fn truncate_body(body: &str) -> String {
+  let body = body.trim();
+  match body.char_indices().nth(MAX_BODY_DISPLAY_CHARS) {
+    Some((end, _)) => format!("{}...", &body[..end]),
+    None => body.to_string(),
+  }
+}
+
+/// Prepended to a response body that is not valid UTF-8.
+const INVALID_UTF8_NOTE: &str = "[body is not valid UTF-8, shown lossily]";
+
+/// Decodes a response body for an error message.
+///
+/// Legacy error pages are sometimes served in Windows-1251. Rather than fail or drop such a
+/// body, its invalid bytes are replaced with U+FFFD and a note saying so is put in front, where
+/// truncation will not cut it off.
+pub(crate) fn decode_body(body: &[u8]) -> String {
+  match std::str::from_utf8(body) {
+    Ok(body) => body.to_string(),
+    Err(_) => format!("{INVALID_UTF8_NOTE} {}", String::from_utf8_lossy(body)),
+  }
+}
+
+/// Returns the start of a response body, for errors that carry an excerpt of it.
+pub(crate) fn body_snippet(body: &[u8]) -> String {
+  truncate_body(&decode_body(body))
+}
+
+/// The errors returned by the crate.
+///
+/// Errors that arise from a request to the registry, from the transport up to parsing the
+/// response, are wrapped in [`Error::Request`] together with the URL that was requested. Use
+/// [`Error::inner`] to match on the underlying error wherever it came from.
+///
+/// Every variant that wraps another error returns it from [`std::error::Error::source`], so
+/// the full cause of a failure can be reported by walking the chain:
+///
+/// ```rust
+/// use std::error::Error as _;
+/// use libedbo::error::Error;
+///
+/// let parse = "4x".parse::<i32>().unwrap_err();
+/// let err = Error::Request {
+///     url: "https://registry.edbo.gov.ua/api/university/?id=4x".to_string(),
+///     operation: None,
+///     source: Box::new(Error::from(parse.clone())),
+/// };
+///
+/// let mut causes = Vec::new();
+/// let mut source = err.source();
+/// while let Some(cause) = source {
+///     causes.push(cause.to_string());
+///     source = cause.source();
+/// }
+/// assert_eq!(causes, [format!("Invalid integer: {parse}"), parse.to_string()]);
+/// ```
+///
+/// Errors are `Clone`, so the failures of a batch can be collected and shared between tasks.
+/// The `reqwest`, `serde_json` and `csv` errors they carry are not, and are held in an `Arc`.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use libedbo::{EdboClient, SearchParams};
+/// use libedbo::error::Error;
+///
+/// # async fn run(client: &EdboClient) -> Result<(), Error> {
+/// match client.search_university(SearchParams::new().with_id(41)).await {
+///     Ok(university) => println!("{university}"),
+///     Err(err) if matches!(err.inner(), Error::NotFound { .. }) => println!("no such university"),
+///     Err(err) => eprintln!("{} failed: {}", err.url().unwrap_or("request"), err.inner()),
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, thiserror::Error)]
 pub enum Error {
-  #[error("API error: {0}")]
-  ApiError(u16),
+  #[error("API error {status}: {}", truncate_body(body))]
+  ApiError { status: u16, body: String },
+  #[error("Not found: {kind}{}", .id.map(|id| format!(" with ID {id}")).unwrap_or_default())]
+  NotFound { id: Option<i32>, kind: &'static str },
+  /// A search that succeeded but matched nothing, from the `_non_empty` search methods.
+  #[error("No results matched the search")]
+  EmptyResult,
+  #[error("Rate limited by the registry{}", .retry_after.map(|d| format!(", retry after {}s", d.as_secs())).unwrap_or_default())]
+  RateLimited { retry_after: Option<Duration> },
+  /// A transport failure that is not one of the more specific variants below, e.g. a
+  /// connection reset while reading the response.
   #[error("Network error: {0}")]
-  NetworkError(#[from] reqwest::Error),
+  NetworkError(#[source] Arc<reqwest::Error>),
+  /// The request or the connection attempt took longer than the configured timeout.
+  #[error("Request timed out: {0}")]
+  Timeout(#[source] Arc<reqwest::Error>),
+  /// The registry's host name resolved, but no connection could be established to it.
+  #[error("Connection failed: {0}")]
+  Connect(#[source] Arc<reqwest::Error>),
+  /// The registry's host name could not be resolved.
+  #[error("DNS lookup failed: {0}")]
+  Dns(#[source] Arc<reqwest::Error>),
   #[error("Parsing error: {0}")]
-  ParsingError(#[from] serde_json::Error),
+  ParsingError(#[source] Arc<serde_json::Error>),
+  /// A successful response whose body is not JSON, typically an HTML maintenance page.
+  ///
+  /// `content_type` is the `Content-Type` header (empty when it was missing) and `snippet`
+  /// is the start of the body.
+  #[error("Expected JSON but received {}: {snippet}", if content_type.is_empty() { "no content type" } else { content_type })]
+  UnexpectedContentType { content_type: String, snippet: String },
+  #[cfg(feature = "csv")]
+  #[error("CSV error: {0}")]
+  CsvError(#[source] Arc<csv::Error>),
+  /// A number that could not be parsed from the registry's text, e.g. a malformed ID string.
+  #[error("Invalid integer: {0}")]
+  IntParse(#[from] std::num::ParseIntError),
+  #[error("Invalid {kind}: {value}")]
+  InvalidValue { kind: &'static str, value: String },
+  /// An ID above the client's [`max_id`](crate::EdboClientBuilder::max_id), rejected
+  /// without sending a request.
+  #[error("Invalid ID {id}: registry IDs are at most {max}")]
+  InvalidId { id: i32, max: i32 },
+  #[error("Request cancelled")]
+  Cancelled,
+  /// A request to `url` failed with `source`.
+  ///
+  /// `operation` is the lookup the URL belongs to, or `None` for a URL outside the known
+  /// endpoints.
+  #[error("Request to {url} failed: {source}")]
+  Request { url: String, operation: Option<Operation>, #[source] source: Box<Error> },
   #[error("Error: {0}")]
   OtherError(String),
-}
\ No newline at end of file
+}
+
+/// Sorts a transport failure into `Timeout`, `Dns`, `Connect` or the catch-all `NetworkError`.
+impl From<reqwest::Error> for Error {
+  fn from(err: reqwest::Error) -> Self {
+    if err.is_timeout() {
+      Error::Timeout(Arc::new(err))
+    } else if is_dns_error(&err) {
+      Error::Dns(Arc::new(err))
+    } else if is_connect(&err) {
+      Error::Connect(Arc::new(err))
+    } else {
+      Error::NetworkError(Arc::new(err))
+    }
+  }
+}
+
+impl From<serde_json::Error> for Error {
+  fn from(err: serde_json::Error) -> Self {
+    Error::ParsingError(Arc::new(err))
+  }
+}
+
+#[cfg(feature = "csv")]
+impl From<csv::Error> for Error {
+  fn from(err: csv::Error) -> Self {
+    Error::CsvError(Arc::new(err))
+  }
+}
+
+/// Returns true if a connection failed because the host name could not be resolved.
+///
+/// reqwest has no dedicated flag for this, so the error's source chain is searched for the
+/// messages produced by hyper's resolver and by `getaddrinfo`.
+fn is_dns_error(err: &reqwest::Error) -> bool {
+  if !is_connect(err) {
+    return false;
+  }
+  let mut source = std::error::Error::source(err);
+  while let Some(cause) = source {
+    let message = cause.to_string();
+    if message.contains("dns error") || message.contains("failed to lookup address") {
+      return true;
+    }
+    source = cause.source();
+  }
+  false
+}
+
+/// Returns true if the error came from establishing a connection.
+///
+/// reqwest only reports this on native targets; in the browser the fetch API gives no such
+/// detail, so connection failures there surface as `NetworkError`.
+#[cfg(not(target_arch = "wasm32"))]
+fn is_connect(err: &reqwest::Error) -> bool {
+  err.is_connect()
+}
+
+#[cfg(target_arch = "wasm32")]
+fn is_connect(_err: &reqwest::Error) -> bool {
+  false
+}
+
+impl Error {
+  /// Returns the underlying error, looking through the [`Error::Request`] wrapper.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::error::Error;
+  ///
+  /// assert!(matches!(Error::Cancelled.inner(), Error::Cancelled));
+  /// ```
+  pub fn inner(&self) -> &Error {
+    match self {
+      Error::Request { source, .. } => source.inner(),
+      other => other,
+    }
+  }
+
+  /// Returns a short, stable name for the kind of error, e.g. `"timeout"` or `"not_found"`,
+  /// looking through the [`Error::Request`] wrapper.
+  ///
+  /// The names are suitable as metric labels; see [`EdboMetrics`](crate::EdboMetrics).
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::error::Error;
+  ///
+  /// assert_eq!(Error::RateLimited { retry_after: None }.kind(), "rate_limited");
+  /// ```
+  pub fn kind(&self) -> &'static str {
+    match self {
+      Error::ApiError { .. } => "api_error",
+      Error::NotFound { .. } => "not_found",
+      Error::EmptyResult => "empty_result",
+      Error::RateLimited { .. } => "rate_limited",
+      Error::NetworkError(_) => "network",
+      Error::Timeout(_) => "timeout",
+      Error::Connect(_) => "connect",
+      Error::Dns(_) => "dns",
+      Error::ParsingError(_) => "parsing",
+      Error::UnexpectedContentType { .. } => "unexpected_content_type",
+      #[cfg(feature = "csv")]
+      Error::CsvError(_) => "csv",
+      Error::IntParse(_) => "int_parse",
+      Error::InvalidValue { .. } => "invalid_value",
+      Error::InvalidId { .. } => "invalid_id",
+      Error::Cancelled => "cancelled",
+      Error::OtherError(_) => "other",
+      Error::Request { source, .. } => source.kind(),
+    }
+  }
+
+  /// Returns the URL of the request that failed, if the error came from one.
+  pub fn url(&self) -> Option<&str> {
+    match self {
+      Error::Request { url, .. } => Some(url),
+      _ => None,
+    }
+  }
+
+  /// Wraps the error in [`Error::Request`] for `url`, unless it is already wrapped.
+  pub(crate) fn in_request(self, url: &str) -> Self {
+    match self {
+      Error::Request { .. } => self,
+      other => Error::Request { url: url.to_string(), operation: Operation::from_url(url), source: Box::new(other) },
+    }
+  }
+
+  /// Attaches the requested resource kind and ID to a `NotFound` error, leaving other errors untouched.
+  pub(crate) fn for_resource(self, kind: &'static str, id: Option<i32>) -> Self {
+    match self {
+      Error::NotFound { .. } => Error::NotFound { id, kind },
+      Error::Request { url, operation, source } => Error::Request { url, operation, source: Box::new(source.for_resource(kind, id)) },
+      other => other,
+    }
+  }
+}
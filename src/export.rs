@@ -0,0 +1,101 @@
+use std::io::Write;
+
+use crate::error::Error;
+use crate::model::{Institution, UniversityBrief};
+
+/// The UTF-8 byte order mark, which makes Excel detect the encoding of Cyrillic text.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Options for the CSV writers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CsvOptions {
+  /// Write a UTF-8 byte order mark before the header row. Enable it for files that will be
+  /// opened in Excel, which otherwise misreads Cyrillic text.
+  pub bom: bool,
+}
+
+/// Writes universities as CSV with a header row and one row per university.
+///
+/// The columns are `name`, `id`, `region`, `address`, `phone`, `email` and `site`.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::{write_universities_csv, UniversityBrief};
+///
+/// let universities: Vec<UniversityBrief> = Vec::new();
+/// let mut out = Vec::new();
+/// write_universities_csv(&universities, &mut out).unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), "name,id,region,address,phone,email,site\n");
+/// ```
+///
+/// # Errors
+///
+/// Returns `Error::CsvError` if writing fails.
+pub fn write_universities_csv<W: Write>(universities: &[UniversityBrief], writer: W) -> Result<(), Error> {
+  write_universities_csv_with_options(universities, writer, CsvOptions::default())
+}
+
+/// Writes universities as CSV, like [`write_universities_csv`], with the given options.
+pub fn write_universities_csv_with_options<W: Write>(
+  universities: &[UniversityBrief],
+  writer: W,
+  options: CsvOptions,
+) -> Result<(), Error> {
+  let mut csv = csv_writer(writer, options)?;
+  csv.write_record(["name", "id", "region", "address", "phone", "email", "site"])?;
+  for uni in universities {
+    csv.write_record([
+      uni.university_name.as_str(),
+      &uni.university_id.to_string(),
+      &uni.region_name_u,
+      &uni.university_address_u,
+      &uni.university_phone,
+      &uni.university_email,
+      &uni.university_site,
+    ])?;
+  }
+  csv.flush().map_err(csv::Error::from)?;
+  Ok(())
+}
+
+/// Writes schools as CSV with a header row and one row per institution.
+///
+/// The columns are `name`, `id`, `region`, `address`, `phone`, `email` and `site`.
+///
+/// # Errors
+///
+/// Returns `Error::CsvError` if writing fails.
+pub fn write_institutions_csv<W: Write>(institutions: &[Institution], writer: W) -> Result<(), Error> {
+  write_institutions_csv_with_options(institutions, writer, CsvOptions::default())
+}
+
+/// Writes schools as CSV, like [`write_institutions_csv`], with the given options.
+pub fn write_institutions_csv_with_options<W: Write>(
+  institutions: &[Institution],
+  writer: W,
+  options: CsvOptions,
+) -> Result<(), Error> {
+  let mut csv = csv_writer(writer, options)?;
+  csv.write_record(["name", "id", "region", "address", "phone", "email", "site"])?;
+  for institution in institutions {
+    csv.write_record([
+      institution.institution_name.as_str(),
+      &institution.institution_id.to_string(),
+      &institution.region_name,
+      &institution.address,
+      &institution.phone,
+      &institution.email,
+      &institution.website,
+    ])?;
+  }
+  csv.flush().map_err(csv::Error::from)?;
+  Ok(())
+}
+
+fn csv_writer<W: Write>(mut writer: W, options: CsvOptions) -> Result<csv::Writer<W>, Error> {
+  if options.bom {
+    writer.write_all(UTF8_BOM).map_err(csv::Error::from)?;
+  }
+  Ok(csv::Writer::from_writer(writer))
+}
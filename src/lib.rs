@@ -11,28 +11,65 @@
 //! - Type-safe API with proper error handling
 //! - Coverage for all regions of Ukraine
 //!
+//! ## Cargo Features
+//!
+//! | Feature        | Default | Enables                                                               |
+//! |----------------|---------|-----------------------------------------------------------------------|
+//! | `blocking`     | yes     | The blocking free functions and the [`blocking`] module               |
+//! | `compression`  | yes     | gzip and brotli response compression                                  |
+//! | `chrono`       | no      | `close_date` as a `chrono::NaiveDate`                                 |
+//! | `csv`          | no      | CSV export of universities and institutions                           |
+//! | `extra-fields` | no      | An `extra` map on the records holding keys the crate does not model   |
+//! | `socks`        | no      | SOCKS5 proxies                                                        |
+//! | `tracing`      | no      | A `tracing` span per request                                          |
+//! | `url`          | no      | `website_url` helpers returning `url::Url`                            |
+//!
+//! Async-only users can turn off `blocking` to avoid building reqwest's blocking client:
+//!
+//! ```toml
+//! libedbo = { version = "0.1", default-features = false, features = ["compression"] }
+//! ```
+//!
+//! ## WebAssembly
+//!
+//! The async API builds for `wasm32-unknown-unknown`, where reqwest sends requests through
+//! the browser's `fetch`. Disable the default features, since the blocking client does not
+//! exist there:
+//!
+//! ```toml
+//! libedbo = { version = "0.1", default-features = false }
+//! ```
+//!
+//! and check the build with
+//! `cargo build --target wasm32-unknown-unknown --no-default-features`. On wasm32 the timeout
+//! and proxy options of [`EdboClientBuilder`] are not available, the disk cache is inert, and
+//! the `tracing` feature is not supported.
+//!
 //! ## Quick Start
 //!
-//! ```rust
+//! ```rust,no_run
 //! use libedbo::{SearchParams, Region, UniversityCategory};
 //!
+//! # fn main() -> Result<(), libedbo::error::Error> {
 //! // Create search parameters for universities in Kyiv
 //! let params = SearchParams::new()
 //!     .with_region(Region::KyivCity)
 //!     .with_university_category(UniversityCategory::HigherEducationInstitutions);
 //!
-//! // Search universities (blocking)
+//! // Search universities (blocking, requires the default `blocking` feature)
 //! let universities = libedbo::search_universities(params)?;
 //!
 //! // Print university names
 //! for uni in universities {
 //!     println!("{}: {}", uni.university_id, uni.university_name);
 //! }
+//! # Ok(())
+//! # }
 //! ```
 //!
 //! ## Async Usage
 //!
-//! ```rust
+//! ```rust,no_run
 //! use libedbo::{SearchParams, Region, UniversityCategory};
 //!
 //! #[tokio::main]
@@ -46,21 +83,93 @@
 //! }
 //! ```
 //!
-use reqwest::{blocking, Client};
-use serde::de::DeserializeOwned;
+//! ## Reusing a Client
+//!
+//! The free functions share a lazily-initialized client, created on the first call, so
+//! repeated calls reuse its connections. It identifies itself as `libedbo/<version>` and
+//! gives up on a request after 30 seconds. Applications that want control over the
+//! underlying connection pool can create an [`EdboClient`] (or a [`blocking::EdboClient`])
+//! and call the same searches as methods on it.
+//!
+//! ## Cancellation
+//!
+//! The async searches are cancel-safe: dropping a future aborts its request and any pending
+//! retries, so they can be raced against other work with `tokio::select!`. For code that
+//! already tracks shutdown with a [`CancellationToken`],
+//! [`search_university_async_with_cancel`] resolves to [`error::Error::Cancelled`] as soon as
+//! the token fires.
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//! use libedbo::SearchParams;
+//!
+//! # async fn run() -> Result<(), libedbo::error::Error> {
+//! tokio::select! {
+//!     university = libedbo::search_university_async(SearchParams::new().with_id(41)) => {
+//!         println!("{}", university?.university_name);
+//!     }
+//!     _ = tokio::time::sleep(Duration::from_secs(5)) => println!("gave up"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+use std::time::Duration;
+use reqwest::header::{HeaderMap, CONTENT_TYPE};
+use reqwest::{Client, StatusCode};
+use serde_json::Value;
 
 mod model;
 mod search;
+mod client;
+mod retry;
+mod transport;
+mod cache;
+mod conditional;
+mod memory;
+mod metrics;
+#[cfg(not(target_arch = "wasm32"))]
+mod ratelimit;
+mod collation;
+mod page;
+mod provenance;
+mod sweep;
+mod tree;
+mod camel;
+#[cfg(feature = "csv")]
+mod export;
+mod trace;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod error;
+pub mod codec;
+pub mod prelude;
 pub use model::*;
 pub use search::*;
+pub use client::*;
+pub use transport::*;
+pub use cache::DEFAULT_CACHE_TTL;
+pub use collation::ukrainian_cmp;
+pub use page::Page;
+pub use provenance::SearchResult;
+pub use sweep::SweepResult;
+pub use tree::UniversityTree;
+pub use camel::CamelCase;
+pub use metrics::EdboMetrics;
+#[cfg(feature = "csv")]
+pub use export::*;
+pub use tokio_util::sync::CancellationToken;
 use error::Error;
+use trace::RequestTrace;
+use conditional::Validators;
 
 const BASE_URL: &str = "https://registry.edbo.gov.ua";
 const UNIVERSITIES_ENDPOINT: &str = "/api/universities";
 const UNIVERSITY_ENDPOINT: &str = "/api/university";
 const INSTITUTIONS_ENDPOINT: &str = "/api/institutions";
 const SCHOOL_ENDPOINT: &str = "/api/school";
+const UNIVERSITY_PAGE: &str = "/university";
+const SCHOOL_PAGE: &str = "/institution";
 
 /// Validates that an Option contains a value and returns it, or an error with the field name.
 ///
@@ -78,75 +187,161 @@ const SCHOOL_ENDPOINT: &str = "/api/school";
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,ignore
 /// let value: Option<i32> = Some(42);
 /// let result = assert_some(value, "example_field")?;
 /// assert_eq!(result, 42);
 /// ```
-fn assert_some<T>(option: Option<T>, field: &str) -> Result<T, Error> {
+pub(crate) fn assert_some<T>(option: Option<T>, field: &str) -> Result<T, Error> {
   option.ok_or_else(|| Error::OtherError(format!("{} cannot be None", field)))
 }
 
-/// Makes an asynchronous HTTP GET request to the EDBO API and deserializes the response.
+/// Makes an asynchronous HTTP GET request to the EDBO API and parses the response as JSON.
 ///
 /// # Arguments
 ///
+/// * `http` - The client whose connection pool the request is sent through
 /// * `url` - The complete URL to request, including query parameters
+/// * `validators` - Where to remember `ETag`/`Last-Modified` validators, if conditional
+///   requests are enabled
+/// * `timeout` - A total timeout for this request, replacing the one `http` was built with
 ///
 /// # Returns
 ///
-/// * `Ok(T)` - Successfully deserialized response
-/// * `Err(Error)` - Request or deserialization error
-///
-/// # Type Parameters
-///
-/// * `T` - The type to deserialize the response into, must implement DeserializeOwned
+/// * `Ok(Value)` - The response body, or the remembered body on a `304 Not Modified`
+/// * `Err(Error)` - Request or parsing error
 ///
 /// # Notes
 ///
 /// This function will return an error if:
 /// - The HTTP request fails
-/// - The response status is not successful (2xx)
-/// - The response cannot be deserialized into type T
-async fn make_request<T: DeserializeOwned>(url: String) -> Result<T, Error> {
-  let response = Client::new().get(&url).send().await?;
-  if response.status().is_success() {
-    Ok(response.json().await?)
+/// - The response status is 404, as `Error::NotFound`
+/// - The response status is 429, as `Error::RateLimited` carrying the `Retry-After` delay
+/// - The response status is not successful (2xx) or an answerable 304, as `Error::ApiError`
+///   carrying the response body
+/// - The response body is not JSON
+///
+/// Every error is wrapped in `Error::Request` carrying the URL.
+///
+/// With the `tracing` feature, each request is recorded in an `edbo_request` span carrying
+/// the endpoint, URL, HTTP status and elapsed time, and failures emit a `warn` event.
+pub(crate) async fn make_request(http: &Client, url: String, validators: Option<&Validators>, timeout: Option<Duration>) -> Result<Value, Error> {
+  let trace = RequestTrace::start(&url);
+  let result = trace.instrument(send_request(http, &url, validators, timeout, &trace)).await;
+  trace.finish(&result);
+  result.map_err(|err| err.in_request(&url))
+}
+
+async fn send_request(http: &Client, url: &str, validators: Option<&Validators>, timeout: Option<Duration>, trace: &RequestTrace) -> Result<Value, Error> {
+  let mut request = http.get(url);
+  if let Some(validators) = validators {
+    request = request.headers(validators.conditional_headers(url));
+  }
+  if let Some(timeout) = timeout {
+    request = request.timeout(timeout);
+  }
+  let response = request.send().await?;
+  let status = response.status();
+  trace.status(status.as_u16());
+  let headers = response.headers().clone();
+  let body = response.bytes().await?;
+  handle_response(status, &headers, &body, url, validators)
+}
+
+/// Turns a response into its JSON body or the matching error.
+///
+/// Both request paths read the whole body before calling this, so success and failure see the
+/// same bytes: a successful body is decoded according to its `Content-Type`, and the body of a
+/// failed request is kept in `Error::ApiError`.
+fn handle_response(status: StatusCode, headers: &HeaderMap, body: &[u8], url: &str, validators: Option<&Validators>) -> Result<Value, Error> {
+  if let Some(body) = not_modified_body(status, url, validators) {
+    Ok(body)
+  } else if status.is_success() {
+    let body = codec::decode_json(content_type(headers), body)?;
+    if let Some(validators) = validators {
+      validators.store(url, headers, &body);
+    }
+    Ok(body)
+  } else if status == StatusCode::NOT_FOUND {
+    Err(Error::NotFound { id: None, kind: "resource" })
+  } else if status == StatusCode::TOO_MANY_REQUESTS {
+    Err(Error::RateLimited { retry_after: retry::retry_after(headers) })
   } else {
-    Err(Error::ApiError(response.status().as_u16()))
+    Err(Error::ApiError { status: status.as_u16(), body: error::decode_body(body) })
   }
 }
 
-/// Makes a blocking HTTP GET request to the EDBO API and deserializes the response.
+/// Returns the remembered body for `url` if `status` is `304 Not Modified`.
+fn not_modified_body(status: StatusCode, url: &str, validators: Option<&Validators>) -> Option<Value> {
+  if status == StatusCode::NOT_MODIFIED {
+    validators?.body(url)
+  } else {
+    None
+  }
+}
+
+/// Returns the `Content-Type` header of a response, or an empty string if it is missing.
+fn content_type(headers: &HeaderMap) -> String {
+  headers.get(CONTENT_TYPE).and_then(|value| value.to_str().ok()).unwrap_or_default().to_string()
+}
+
+/// Makes a blocking HTTP GET request to the EDBO API and parses the response as JSON.
 ///
 /// This is the blocking version of `make_request`.
 ///
 /// # Arguments
 ///
+/// * `http` - The client whose connection pool the request is sent through
 /// * `url` - The complete URL to request, including query parameters
+/// * `validators` - Where to remember `ETag`/`Last-Modified` validators, if conditional
+///   requests are enabled
+/// * `timeout` - A total timeout for this request, replacing the one `http` was built with
 ///
 /// # Returns
 ///
-/// * `Ok(T)` - Successfully deserialized response
-/// * `Err(Error)` - Request or deserialization error
-///
-/// # Type Parameters
-///
-/// * `T` - The type to deserialize the response into, must implement DeserializeOwned
-fn make_request_blocking<T: DeserializeOwned>(url: String) -> Result<T, Error> {
-  let response = blocking::Client::new().get(&url).send()?;
-  if response.status().is_success() {
-    Ok(response.json()?)
-  } else {
-    Err(Error::ApiError(response.status().as_u16()))
+/// * `Ok(Value)` - The response body, or the remembered body on a `304 Not Modified`
+/// * `Err(Error)` - Request or parsing error
+#[cfg(feature = "blocking")]
+pub(crate) fn make_request_blocking(
+  http: &reqwest::blocking::Client,
+  url: String,
+  validators: Option<&Validators>,
+  timeout: Option<Duration>,
+) -> Result<Value, Error> {
+  let trace = RequestTrace::start(&url);
+  let result = trace.in_scope(|| send_request_blocking(http, &url, validators, timeout, &trace));
+  trace.finish(&result);
+  result.map_err(|err| err.in_request(&url))
+}
+
+#[cfg(feature = "blocking")]
+fn send_request_blocking(
+  http: &reqwest::blocking::Client,
+  url: &str,
+  validators: Option<&Validators>,
+  timeout: Option<Duration>,
+  trace: &RequestTrace,
+) -> Result<Value, Error> {
+  let mut request = http.get(url);
+  if let Some(validators) = validators {
+    request = request.headers(validators.conditional_headers(url));
+  }
+  if let Some(timeout) = timeout {
+    request = request.timeout(timeout);
   }
+  let response = request.send()?;
+  let status = response.status();
+  trace.status(status.as_u16());
+  let headers = response.headers().clone();
+  let body = response.bytes()?;
+  handle_response(status, &headers, &body, url, validators)
 }
 
 /// Asynchronously searches for universities based on provided parameters.
 ///
 /// # Arguments
 ///
-/// * `param` - Search parameters including region and university category
+/// * `param` - Search parameters including region, university category and an optional name filter
 ///
 /// # Returns
 ///
@@ -155,7 +350,7 @@ fn make_request_blocking<T: DeserializeOwned>(url: String) -> Result<T, Error> {
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::{SearchParams, Region, UniversityCategory};
 ///
 /// #[tokio::main]
@@ -169,10 +364,7 @@ fn make_request_blocking<T: DeserializeOwned>(url: String) -> Result<T, Error> {
 /// }
 /// ```
 pub async fn search_universities_async(param: SearchParams) -> Result<Vec<UniversityBrief>, Error> {
-  let ut = assert_some(param.university_category, "university_category")?;
-  let lc = assert_some(param.region, "region")?;
-  let url = format!("{BASE_URL}{UNIVERSITIES_ENDPOINT}?ut={ut}&lc={lc}&exp=json");
-  make_request(url).await
+  EdboClient::shared().search_universities(param).await
 }
 
 /// Searches for universities based on provided parameters (blocking version).
@@ -181,7 +373,7 @@ pub async fn search_universities_async(param: SearchParams) -> Result<Vec<Univer
 ///
 /// # Arguments
 ///
-/// * `param` - Search parameters including region and university category
+/// * `param` - Search parameters including region, university category and an optional name filter
 ///
 /// # Returns
 ///
@@ -190,20 +382,21 @@ pub async fn search_universities_async(param: SearchParams) -> Result<Vec<Univer
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::{SearchParams, Region, UniversityCategory};
 ///
+/// # fn main() -> Result<(), libedbo::error::Error> {
 /// let params = SearchParams::new()
 ///     .with_region(Region::LvivOblast)
 ///     .with_university_category(UniversityCategory::HigherEducationInstitutions);
 ///
 /// let universities = libedbo::search_universities(params)?;
+/// # Ok(())
+/// # }
 /// ```
+#[cfg(feature = "blocking")]
 pub fn search_universities(param: SearchParams) -> Result<Vec<UniversityBrief>, Error> {
-  let ut = assert_some(param.university_category, "university_category")?;
-  let lc = assert_some(param.region, "region")?;
-  let url = format!("{BASE_URL}{UNIVERSITIES_ENDPOINT}?ut={ut}&lc={lc}&exp=json");
-  make_request_blocking(url)
+  blocking::EdboClient::shared().search_universities(param)
 }
 
 /// Asynchronously retrieves detailed information about a specific university.
@@ -219,7 +412,7 @@ pub fn search_universities(param: SearchParams) -> Result<Vec<UniversityBrief>,
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::SearchParams;
 ///
 /// #[tokio::main]
@@ -235,14 +428,40 @@ pub fn search_universities(param: SearchParams) -> Result<Vec<UniversityBrief>,
 /// Returns an error if:
 /// - The ID is missing or less than 1
 /// - The API request fails
-/// - The university is not found
+/// - The university is not found, as `Error::NotFound` inside `Error::Request`
 pub async fn search_university_async(param: SearchParams) -> Result<University, Error> {
-  let id = assert_some(param.id, "id")?;
-  if id < 1 {
-    return Err(Error::OtherError("University ID must be positive".to_string()));
-  }
-  let url = format!("{BASE_URL}{UNIVERSITY_ENDPOINT}?id={id}&exp=json");
-  make_request(url).await
+  EdboClient::shared().search_university(param).await
+}
+
+/// Retrieves detailed information about a specific university, giving up when `token` is cancelled.
+///
+/// # Arguments
+///
+/// * `param` - Search parameters containing the university ID
+/// * `token` - Cancelling it aborts the request, including any retries still pending
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::{CancellationToken, SearchParams};
+/// use libedbo::error::Error;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let token = CancellationToken::new();
+///     token.cancel();
+///
+///     let result = libedbo::search_university_async_with_cancel(SearchParams::new().with_id(41), &token).await;
+///     assert!(matches!(result, Err(Error::Cancelled)));
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns `Error::Cancelled` if the token fires before the response arrives, and otherwise the
+/// same errors as [`search_university_async`].
+pub async fn search_university_async_with_cancel(param: SearchParams, token: &CancellationToken) -> Result<University, Error> {
+  EdboClient::shared().search_university_with_cancel(param, token).await
 }
 
 /// Retrieves detailed information about a specific university (blocking version).
@@ -260,11 +479,14 @@ pub async fn search_university_async(param: SearchParams) -> Result<University,
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::SearchParams;
 ///
+/// # fn main() -> Result<(), libedbo::error::Error> {
 /// let params = SearchParams::new().with_id(1234);
 /// let university = libedbo::search_university(params)?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// # Errors
@@ -272,14 +494,10 @@ pub async fn search_university_async(param: SearchParams) -> Result<University,
 /// Returns an error if:
 /// - The ID is missing or less than 1
 /// - The API request fails
-/// - The university is not found
+/// - The university is not found, as `Error::NotFound` inside `Error::Request`
+#[cfg(feature = "blocking")]
 pub fn search_university(param: SearchParams) -> Result<University, Error> {
-  let id = assert_some(param.id, "id")?;
-  if id < 1 {
-    return Err(Error::OtherError("University ID must be positive".to_string()));
-  }
-  let url = format!("{BASE_URL}{UNIVERSITY_ENDPOINT}?id={id}&exp=json");
-  make_request_blocking(url)
+  blocking::EdboClient::shared().search_university(param)
 }
 
 /// Asynchronously searches for secondary education institutions based on provided parameters.
@@ -295,7 +513,7 @@ pub fn search_university(param: SearchParams) -> Result<University, Error> {
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::{SearchParams, Region, InstitutionCategory};
 ///
 /// #[tokio::main]
@@ -309,10 +527,7 @@ pub fn search_university(param: SearchParams) -> Result<University, Error> {
 /// }
 /// ```
 pub async fn search_institutions_async(param: SearchParams) -> Result<Vec<Institution>, Error> {
-  let ut = assert_some(param.institution_category, "institution_category")?;
-  let lc = assert_some(param.region, "region")?;
-  let url = format!("{BASE_URL}{INSTITUTIONS_ENDPOINT}?ut={ut}&lc={lc}&exp=json");
-  make_request(url).await
+  EdboClient::shared().search_institutions(param).await
 }
 
 /// Searches for secondary education institutions based on provided parameters (blocking version).
@@ -330,20 +545,21 @@ pub async fn search_institutions_async(param: SearchParams) -> Result<Vec<Instit
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::{SearchParams, Region, InstitutionCategory};
 ///
+/// # fn main() -> Result<(), libedbo::error::Error> {
 /// let params = SearchParams::new()
 ///     .with_region(Region::LvivOblast)
 ///     .with_institution_category(InstitutionCategory::GeneralSecondaryEducationInstitutions);
 ///
 /// let schools = libedbo::search_institutions(params)?;
+/// # Ok(())
+/// # }
 /// ```
+#[cfg(feature = "blocking")]
 pub fn search_institutions(param: SearchParams) -> Result<Vec<Institution>, Error> {
-  let ut = assert_some(param.institution_category, "institution_category")?;
-  let lc = assert_some(param.region, "region")?;
-  let url = format!("{BASE_URL}{INSTITUTIONS_ENDPOINT}?ut={ut}&lc={lc}&exp=json");
-  make_request_blocking(url)
+  blocking::EdboClient::shared().search_institutions(param)
 }
 
 /// Asynchronously retrieves detailed information about a specific school.
@@ -359,7 +575,7 @@ pub fn search_institutions(param: SearchParams) -> Result<Vec<Institution>, Erro
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::SearchParams;
 ///
 /// #[tokio::main]
@@ -375,14 +591,9 @@ pub fn search_institutions(param: SearchParams) -> Result<Vec<Institution>, Erro
 /// Returns an error if:
 /// - The ID is missing or less than 1
 /// - The API request fails
-/// - The school is not found
+/// - The school is not found, as `Error::NotFound` inside `Error::Request`
 pub async fn search_school_async(param: SearchParams) -> Result<Institution, Error> {
-  let id = assert_some(param.id, "id")?;
-  if id < 1 {
-    return Err(Error::OtherError("School ID must be positive".to_string()));
-  }
-  let url = format!("{BASE_URL}{SCHOOL_ENDPOINT}?id={id}&exp=json");
-  make_request(url).await
+  EdboClient::shared().search_school(param).await
 }
 
 /// Retrieves detailed information about a specific school (blocking version).
@@ -400,11 +611,14 @@ pub async fn search_school_async(param: SearchParams) -> Result<Institution, Err
 ///
 /// # Examples
 ///
-/// ```rust
+/// ```rust,no_run
 /// use libedbo::SearchParams;
 ///
+/// # fn main() -> Result<(), libedbo::error::Error> {
 /// let params = SearchParams::new().with_id(1234);
 /// let school = libedbo::search_school(params)?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// # Errors
@@ -412,12 +626,30 @@ pub async fn search_school_async(param: SearchParams) -> Result<Institution, Err
 /// Returns an error if:
 /// - The ID is missing or less than 1
 /// - The API request fails
-/// - The school is not found
+/// - The school is not found, as `Error::NotFound` inside `Error::Request`
+#[cfg(feature = "blocking")]
 pub fn search_school(param: SearchParams) -> Result<Institution, Error> {
-  let id = assert_some(param.id, "id")?;
-  if id < 1 {
-    return Err(Error::OtherError("School ID must be positive".to_string()));
-  }
-  let url = format!("{BASE_URL}{SCHOOL_ENDPOINT}?id={id}&exp=json");
-  make_request_blocking(url)
-}
\ No newline at end of file
+  blocking::EdboClient::shared().search_school(param)
+}
+
+/// Lists the universities of `category` in every region of Ukraine without an async runtime.
+///
+/// Regions are fetched on up to [`DEFAULT_MAX_CONCURRENCY`] worker threads; use
+/// [`blocking::EdboClient::search_all_regions`] to choose the number. A region that fails is
+/// reported in [`SweepResult::failures`] without discarding the others.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use libedbo::UniversityCategory;
+///
+/// # fn main() -> Result<(), libedbo::error::Error> {
+/// let sweep = libedbo::search_all_regions(UniversityCategory::ScientificInstitutes);
+/// println!("{} institutes, {} regions failed", sweep.universities.len(), sweep.failures.len());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "blocking")]
+pub fn search_all_regions(category: UniversityCategory) -> SweepResult {
+  blocking::EdboClient::shared().search_all_regions(category)
+}
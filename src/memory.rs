@@ -0,0 +1,100 @@
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Identifies a cached record by the endpoint it was fetched from and its ID.
+type Key = (&'static str, i32);
+
+/// A bounded, least-recently-used cache of deserialized records, shared by a client and its clones.
+///
+/// Values are stored typed, so a hit is a clone of the record rather than a fresh parse.
+#[derive(Debug)]
+pub(crate) struct MemoryCache {
+  capacity: usize,
+  inner: Mutex<Lru>,
+  hits: AtomicU64,
+  misses: AtomicU64,
+}
+
+#[derive(Debug, Default)]
+struct Lru {
+  tick: u64,
+  entries: HashMap<Key, (u64, Arc<dyn Any + Send + Sync>)>,
+  /// The keys ordered by last use, oldest first.
+  recency: BTreeMap<u64, Key>,
+}
+
+impl MemoryCache {
+  /// Creates a cache holding at most `capacity` records. `capacity` must be at least 1.
+  pub(crate) fn new(capacity: usize) -> Self {
+    MemoryCache { capacity, inner: Mutex::default(), hits: AtomicU64::new(0), misses: AtomicU64::new(0) }
+  }
+
+  /// Returns a copy of the record stored under `endpoint` and `id`, marking it as recently used.
+  pub(crate) fn get<T: Clone + 'static>(&self, endpoint: &'static str, id: i32) -> Option<T> {
+    let mut lru = self.lock();
+    let tick = lru.next_tick();
+    let found = match lru.entries.get_mut(&(endpoint, id)) {
+      Some((used, value)) => {
+        let previous = std::mem::replace(used, tick);
+        value.downcast_ref::<T>().cloned().map(|value| (previous, value))
+      }
+      None => None,
+    };
+    match found {
+      Some((previous, value)) => {
+        lru.recency.remove(&previous);
+        lru.recency.insert(tick, (endpoint, id));
+        self.hits.fetch_add(1, Ordering::Relaxed);
+        Some(value)
+      }
+      None => {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        None
+      }
+    }
+  }
+
+  /// Stores `value` under `endpoint` and `id`, evicting the least recently used record if full.
+  pub(crate) fn put<T: Send + Sync + 'static>(&self, endpoint: &'static str, id: i32, value: T) {
+    let mut lru = self.lock();
+    let tick = lru.next_tick();
+    if let Some((previous, _)) = lru.entries.insert((endpoint, id), (tick, Arc::new(value))) {
+      lru.recency.remove(&previous);
+    }
+    lru.recency.insert(tick, (endpoint, id));
+    while lru.entries.len() > self.capacity {
+      let Some((_, oldest)) = lru.recency.pop_first() else { break };
+      lru.entries.remove(&oldest);
+    }
+  }
+
+  /// Returns the number of lookups that found a record.
+  pub(crate) fn hits(&self) -> u64 {
+    self.hits.load(Ordering::Relaxed)
+  }
+
+  /// Returns the number of lookups that found nothing.
+  pub(crate) fn misses(&self) -> u64 {
+    self.misses.load(Ordering::Relaxed)
+  }
+
+  /// Returns the fraction of lookups that were hits, or `None` before the first lookup.
+  pub(crate) fn hit_ratio(&self) -> Option<f64> {
+    let hits = self.hits();
+    let lookups = hits + self.misses();
+    (lookups > 0).then(|| hits as f64 / lookups as f64)
+  }
+
+  fn lock(&self) -> MutexGuard<'_, Lru> {
+    self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+  }
+}
+
+impl Lru {
+  fn next_tick(&mut self) -> u64 {
+    self.tick += 1;
+    self.tick
+  }
+}
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::error::Error;
+
+/// The lowest HTTP status code counted by [`Metrics`].
+const MIN_STATUS: u16 = 100;
+
+/// A snapshot of the counters a client keeps about its requests.
+///
+/// The counters are shared by a client and its clones and only ever grow, so the difference
+/// between two snapshots gives the activity in between. See
+/// [`EdboClient::metrics`](crate::EdboClient::metrics).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EdboMetrics {
+  /// The number of requests handed to the transport, retries included.
+  pub requests: u64,
+  /// The number of responses per HTTP status.
+  ///
+  /// The transport reports a successful response as parsed JSON without its status, so
+  /// successes are counted under 200. Failures without a response, such as timeouts, are
+  /// counted only in `errors`.
+  pub responses_by_status: BTreeMap<u16, u64>,
+  /// The number of failed requests per [`Error::kind`], retried attempts included.
+  pub errors: BTreeMap<&'static str, u64>,
+  /// The number of responses served from the disk cache.
+  pub cache_hits: u64,
+  /// The number of lookups that missed the disk cache and went to the registry.
+  pub cache_misses: u64,
+  /// The number of records served from the memory cache.
+  pub memory_cache_hits: u64,
+  /// The number of single-record lookups that missed the memory cache.
+  pub memory_cache_misses: u64,
+}
+
+/// The live counters behind [`EdboMetrics`].
+///
+/// Requests, statuses and cache lookups are plain atomics. Errors are counted under a lock,
+/// which only failed requests take.
+#[derive(Debug)]
+pub(crate) struct Metrics {
+  requests: AtomicU64,
+  statuses: Vec<AtomicU64>,
+  errors: Mutex<BTreeMap<&'static str, u64>>,
+  cache_hits: AtomicU64,
+  cache_misses: AtomicU64,
+}
+
+impl Default for Metrics {
+  fn default() -> Self {
+    Metrics {
+      requests: AtomicU64::new(0),
+      statuses: (MIN_STATUS..600).map(|_| AtomicU64::new(0)).collect(),
+      errors: Mutex::default(),
+      cache_hits: AtomicU64::new(0),
+      cache_misses: AtomicU64::new(0),
+    }
+  }
+}
+
+impl Metrics {
+  /// Counts a request handed to the transport.
+  pub(crate) fn request(&self) {
+    self.requests.fetch_add(1, Ordering::Relaxed);
+  }
+
+  /// Counts the outcome of a request: its status if the registry answered, and its error
+  /// kind if it failed.
+  pub(crate) fn record<T>(&self, response: &Result<T, Error>) {
+    let Err(error) = response else {
+      self.status(200);
+      return;
+    };
+    match error.inner() {
+      Error::ApiError { status, .. } => self.status(*status),
+      Error::NotFound { .. } => self.status(404),
+      Error::RateLimited { .. } => self.status(429),
+      _ => {}
+    }
+    *self.errors.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).entry(error.kind()).or_default() += 1;
+  }
+
+  /// Counts a disk cache lookup.
+  pub(crate) fn cache_lookup(&self, hit: bool) {
+    let counter = if hit { &self.cache_hits } else { &self.cache_misses };
+    counter.fetch_add(1, Ordering::Relaxed);
+  }
+
+  fn status(&self, status: u16) {
+    if let Some(counter) = status.checked_sub(MIN_STATUS).and_then(|index| self.statuses.get(usize::from(index))) {
+      counter.fetch_add(1, Ordering::Relaxed);
+    }
+  }
+
+  /// Reads the counters. The memory cache keeps its own, which are passed in.
+  pub(crate) fn snapshot(&self, memory_cache_hits: u64, memory_cache_misses: u64) -> EdboMetrics {
+    let responses_by_status = (MIN_STATUS..)
+      .zip(&self.statuses)
+      .map(|(status, counter)| (status, counter.load(Ordering::Relaxed)))
+      .filter(|(_, count)| *count > 0)
+      .collect();
+    EdboMetrics {
+      requests: self.requests.load(Ordering::Relaxed),
+      responses_by_status,
+      errors: self.errors.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone(),
+      cache_hits: self.cache_hits.load(Ordering::Relaxed),
+      cache_misses: self.cache_misses.load(Ordering::Relaxed),
+      memory_cache_hits,
+      memory_cache_misses,
+    }
+  }
+}
@@ -0,0 +1,162 @@
+//! Cleanup of the free-form contact fields the registry returns.
+
+use serde::{Serialize, Deserialize};
+use super::normalize_ws;
+
+/// The contact details of an institution, cleaned up for display.
+///
+/// Built with [`crate::Institution::contact_card`], [`crate::University::contact_card`] or
+/// [`crate::UniversityBrief::contact_card`], which fill it the same way whatever the record:
+///
+/// * `name`, `address` and `director` have their whitespace normalized
+/// * `phone` is in the `+380XXXXXXXXX` form when [`normalize_phone`] can parse it, and is the
+///   registry's text with normalized whitespace otherwise; `None` when the field is blank
+/// * `emails` lists the addresses found by [`split_emails`], valid or not
+/// * `website` is trimmed, and `None` when blank
+///
+/// # Examples
+///
+/// ```rust
+/// # let institution: libedbo::Institution = serde_json::from_value(serde_json::json!({
+/// #     "institution_name": " Ліцей  № 1 ", "institution_id": "137452", "is_checked": "1",
+/// #     "short_name": "Ліцей № 1", "state_name": "працює", "institution_type_name": "ліцей",
+/// #     "university_financing_type_name": "Комунальна", "koatuu_id": "4610136300", "region_name": "Львівська область",
+/// #     "koatuu_name": "м. Львів", "address": "вул. Городоцька, 36", "parent_institution_id": "",
+/// #     "governance_name": "", "phone": "(032) 233-44-55", "fax": "",
+/// #     "email": "lyceum1@ukr.net; ", "website": " lyceum1.lviv.ua ", "boss": "Іваненко Ірина Петрівна", "support_name": "",
+/// #     "is_village": "0", "is_mountain": "0", "is_internat": "0", "approved_count": "900"
+/// # })).unwrap();
+/// let card = institution.contact_card();
+/// assert_eq!(card.name, "Ліцей № 1");
+/// assert_eq!(card.phone.as_deref(), Some("+380322334455"));
+/// assert_eq!(card.emails, ["lyceum1@ukr.net"]);
+/// assert_eq!(card.website.as_deref(), Some("lyceum1.lviv.ua"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContactCard {
+  pub name: String,
+  pub phone: Option<String>,
+  pub emails: Vec<String>,
+  pub website: Option<String>,
+  pub address: String,
+  pub director: String,
+}
+
+impl ContactCard {
+  /// Builds a card from a record's raw contact fields.
+  pub(super) fn from_fields(name: &str, phone: &str, email: &str, website: &str, address: &str, director: &str) -> Self {
+    let raw_phone = normalize_ws(phone);
+    let website = website.trim();
+    ContactCard {
+      name: normalize_ws(name).into_owned(),
+      phone: normalize_phone(phone).or_else(|| (!raw_phone.is_empty()).then(|| raw_phone.into_owned())),
+      emails: split_emails(email),
+      website: (!website.is_empty()).then(|| website.to_string()),
+      address: normalize_ws(address).into_owned(),
+      director: normalize_ws(director).into_owned(),
+    }
+  }
+}
+
+/// Normalizes a Ukrainian phone number to the canonical `+380XXXXXXXXX` form.
+///
+/// Spaces, dashes, dots and parentheses are ignored. Numbers may be written internationally
+/// (`+380 44 123 45 67`), nationally with the trunk prefix (`(044) 123-45-67`) or in the old
+/// `8 044 ...` form. When the field lists several numbers, only the first is used, and an
+/// extension (`доб. 12`) is dropped.
+///
+/// Returns `None` for blank values and for anything that does not have exactly the nine
+/// subscriber digits after the country code, such as numbers written without an area code.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::normalize_phone;
+///
+/// assert_eq!(normalize_phone("(044) 239-33-33").as_deref(), Some("+380442393333"));
+/// assert_eq!(normalize_phone("+38 (044) 239 33 33").as_deref(), Some("+380442393333"));
+/// assert_eq!(normalize_phone("8 044 239-33-33").as_deref(), Some("+380442393333"));
+/// assert_eq!(normalize_phone("0442393333, 0442393334").as_deref(), Some("+380442393333"));
+/// assert_eq!(normalize_phone("239-33-33"), None);
+/// assert_eq!(normalize_phone(""), None);
+/// ```
+pub fn normalize_phone(raw: &str) -> Option<String> {
+  let first = raw.split([',', ';']).next()?;
+  let number = match first.find(char::is_alphabetic) {
+    Some(end) => &first[..end],
+    None => first,
+  };
+  if number.chars().any(|c| !c.is_ascii_digit() && !matches!(c, '+' | ' ' | '-' | '.' | '(' | ')' | '\u{a0}')) {
+    return None;
+  }
+  let digits: String = number.chars().filter(char::is_ascii_digit).collect();
+  let subscriber = match digits.len() {
+    12 if digits.starts_with("380") => &digits[3..],
+    11 if digits.starts_with("80") => &digits[2..],
+    10 if digits.starts_with('0') => &digits[1..],
+    _ => return None,
+  };
+  Some(format!("+380{subscriber}"))
+}
+
+/// Splits an email field into the addresses it lists.
+///
+/// Addresses may be separated by commas, semicolons or whitespace. Each one is trimmed and a
+/// `mailto:` prefix is removed; blank entries are skipped. The addresses are not validated,
+/// see [`is_valid_email`] for that.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::split_emails;
+///
+/// assert_eq!(split_emails("rector@knu.ua; office@knu.ua,"), ["rector@knu.ua", "office@knu.ua"]);
+/// assert!(split_emails(" ").is_empty());
+/// ```
+pub fn split_emails(raw: &str) -> Vec<String> {
+  raw
+    .split(|c: char| matches!(c, ',' | ';') || c.is_whitespace())
+    .map(|email| email.trim_start_matches("mailto:"))
+    .filter(|email| !email.is_empty())
+    .map(str::to_string)
+    .collect()
+}
+
+/// Checks that `email` is a syntactically valid address.
+///
+/// The check is deliberately lightweight: a dot-atom local part, an `@`, and a domain of at
+/// least two dot-separated labels ending in an alphabetic top-level domain. Quoted local parts
+/// and IP-literal domains are rejected, and no DNS lookup is made.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::is_valid_email;
+///
+/// assert!(is_valid_email("pr.office@knu.ua"));
+/// assert!(!is_valid_email("office@knu"));
+/// assert!(!is_valid_email("office@@knu.ua"));
+/// assert!(!is_valid_email("office.@knu.ua"));
+/// ```
+pub fn is_valid_email(email: &str) -> bool {
+  let Some((local, domain)) = email.split_once('@') else {
+    return false;
+  };
+  let local_ok = !local.is_empty()
+    && local.len() <= 64
+    && local.split('.').all(|atom| {
+      !atom.is_empty() && atom.chars().all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c))
+    });
+  let labels: Vec<&str> = domain.split('.').collect();
+  let domain_ok = labels.len() >= 2
+    && domain.len() <= 253
+    && labels.iter().all(|label| {
+      !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+    && labels.last().is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()));
+  local_ok && domain_ok
+}
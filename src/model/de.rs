@@ -0,0 +1,128 @@
+//! Lenient deserializers for registry fields whose JSON representation is inconsistent.
+
+use std::str::FromStr;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
+
+/// A scalar the registry sends either as a JSON string or as a JSON number.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum StrOrNum {
+  Str(String),
+  Num(u64),
+}
+
+/// A boolean the registry sends as `"1"`/`"0"`, `"true"`/`"false"`, a JSON number or a JSON boolean.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Flag {
+  Bool(bool),
+  Num(u64),
+  Str(String),
+}
+
+/// Deserializes a boolean flag, treating blank strings and `null` as `false`.
+pub(crate) fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+  match Option::<Flag>::deserialize(deserializer)? {
+    None => Ok(false),
+    Some(Flag::Bool(b)) => Ok(b),
+    Some(Flag::Num(0)) => Ok(false),
+    Some(Flag::Num(1)) => Ok(true),
+    Some(Flag::Num(n)) => Err(D::Error::custom(format!("invalid flag: {n}"))),
+    Some(Flag::Str(s)) => match s.trim() {
+      "" | "0" | "false" => Ok(false),
+      "1" | "true" => Ok(true),
+      other => Err(D::Error::custom(format!("invalid flag: {other:?}"))),
+    },
+  }
+}
+
+/// Deserializes a non-negative count from a string or a number, treating blank strings and `null` as 0.
+pub(crate) fn count<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u32, D::Error> {
+  match Option::<StrOrNum>::deserialize(deserializer)? {
+    None => Ok(0),
+    Some(StrOrNum::Num(n)) => u32::try_from(n).map_err(|_| D::Error::custom(format!("invalid count: {n}"))),
+    Some(StrOrNum::Str(s)) if s.trim().is_empty() => Ok(0),
+    Some(StrOrNum::Str(s)) => s.trim().parse().map_err(|_| D::Error::custom(format!("invalid count: {s:?}"))),
+  }
+}
+
+/// Deserializes a text field from a string or a number, treating `null` as an empty string.
+///
+/// Fields using this should also be marked `#[serde(default)]` so that a missing key is read as
+/// an empty string too.
+pub(crate) fn text<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+  match Option::<serde_json::Value>::deserialize(deserializer)? {
+    None => Ok(String::new()),
+    Some(serde_json::Value::String(s)) => Ok(s),
+    Some(serde_json::Value::Number(n)) => Ok(n.to_string()),
+    Some(serde_json::Value::Bool(b)) => Ok(b.to_string()),
+    Some(other) => Err(D::Error::custom(format!("invalid text field: {other}"))),
+  }
+}
+
+/// Deserializes an optional ID from a string or a number, treating blank strings as `None`.
+pub(crate) fn optional_id<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: Deserialize<'de>,
+{
+  match Option::<serde_json::Value>::deserialize(deserializer)? {
+    None => Ok(None),
+    Some(serde_json::Value::String(s)) if s.trim().is_empty() => Ok(None),
+    Some(value) => T::deserialize(value).map(Some).map_err(D::Error::custom),
+  }
+}
+
+/// Deserializes an optional unsigned integer from a string or a number, treating blank strings as `None`.
+fn opt_uint<'de, D, T>(deserializer: D, field: &'static str) -> Result<Option<T>, D::Error>
+where
+  D: Deserializer<'de>,
+  T: TryFrom<u64> + FromStr,
+{
+  match Option::<StrOrNum>::deserialize(deserializer)? {
+    None => Ok(None),
+    Some(StrOrNum::Num(n)) => T::try_from(n)
+      .map(Some)
+      .map_err(|_| D::Error::custom(format!("invalid {field}: {n}"))),
+    Some(StrOrNum::Str(s)) if s.trim().is_empty() => Ok(None),
+    Some(StrOrNum::Str(s)) => s
+      .trim()
+      .parse()
+      .map(Some)
+      .map_err(|_| D::Error::custom(format!("invalid {field}: {s:?}"))),
+  }
+}
+
+pub(crate) fn registration_year<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u16>, D::Error> {
+  opt_uint(deserializer, "registration_year")
+}
+
+pub(crate) fn approved_count<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u32>, D::Error> {
+  opt_uint(deserializer, "approved_count")
+}
+
+/// The date format the registry uses for `close_date`.
+#[cfg(feature = "chrono")]
+pub(crate) const CLOSE_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Deserializes `close_date` as a date in [`CLOSE_DATE_FORMAT`], treating blank strings and `null` as `None`.
+///
+/// A trailing time component (`2020-09-01 00:00:00` or `2020-09-01T00:00:00`) is ignored.
+#[cfg(feature = "chrono")]
+pub(crate) fn close_date<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<chrono::NaiveDate>, D::Error> {
+  let Some(raw) = Option::<String>::deserialize(deserializer)? else {
+    return Ok(None);
+  };
+  let raw = raw.trim();
+  if raw.is_empty() {
+    return Ok(None);
+  }
+  let date = match raw.split_once([' ', 'T']) {
+    Some((date, _)) => date,
+    None => raw,
+  };
+  chrono::NaiveDate::parse_from_str(date, CLOSE_DATE_FORMAT)
+    .map(Some)
+    .map_err(|_| D::Error::custom(format!("invalid close_date: {raw:?}, expected {CLOSE_DATE_FORMAT}")))
+}
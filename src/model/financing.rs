@@ -0,0 +1,114 @@
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use super::lang::Lang;
+use super::fold_label;
+
+/// The form of ownership that finances an institution.
+///
+/// The registry reports this as a Ukrainian name in `university_financing_type_name` and does
+/// not accept it as a query parameter, so filtering by it happens in the crate. Names the
+/// crate does not recognize are kept verbatim in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FinancingType {
+  State,     // Державна
+  Communal,  // Комунальна
+  Private,   // Приватна
+  Corporate, // Корпоративна
+  Other(String),
+}
+
+impl fmt::Display for FinancingType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name_en())
+  }
+}
+
+impl FinancingType {
+  /// Every known financing type, in declaration order.
+  pub const ALL: [FinancingType; 4] = [
+    FinancingType::State,
+    FinancingType::Communal,
+    FinancingType::Private,
+    FinancingType::Corporate,
+  ];
+
+  /// Returns the Ukrainian name of the financing type, as used by the registry.
+  ///
+  /// For `Other`, this is the name the registry sent.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::FinancingType;
+  ///
+  /// assert_eq!(FinancingType::Private.name_uk(), "Приватна");
+  /// ```
+  pub fn name_uk(&self) -> &str {
+    match self {
+      FinancingType::State     => "Державна",
+      FinancingType::Communal  => "Комунальна",
+      FinancingType::Private   => "Приватна",
+      FinancingType::Corporate => "Корпоративна",
+      FinancingType::Other(name) => name,
+    }
+  }
+
+  /// Returns the English name of the financing type.
+  ///
+  /// `Other` has no translation, so its Ukrainian name is returned.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::FinancingType;
+  ///
+  /// assert_eq!(FinancingType::State.name_en(), "State");
+  /// ```
+  pub fn name_en(&self) -> &str {
+    match self {
+      FinancingType::State     => "State",
+      FinancingType::Communal  => "Communal",
+      FinancingType::Private   => "Private",
+      FinancingType::Corporate => "Corporate",
+      FinancingType::Other(name) => name,
+    }
+  }
+
+  /// Returns the name of the financing type in `lang`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{FinancingType, Lang};
+  ///
+  /// assert_eq!(FinancingType::State.name(Lang::Uk), "Державна");
+  /// assert_eq!(FinancingType::State.name(Lang::En), "State");
+  /// ```
+  pub fn name(&self, lang: Lang) -> &str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+
+  /// Recognizes a financing type from the name the registry sends, ignoring case, repeated
+  /// and surrounding whitespace, and apostrophe variants.
+  ///
+  /// An unrecognized name becomes `Other`, trimmed.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::FinancingType;
+  ///
+  /// assert_eq!(FinancingType::from_name(" державна "), FinancingType::State);
+  /// assert_eq!(FinancingType::from_name("невідома"), FinancingType::Other("невідома".to_string()));
+  /// ```
+  pub fn from_name(name: &str) -> FinancingType {
+    let folded = fold_label(name);
+    FinancingType::ALL
+      .into_iter()
+      .find(|financing| fold_label(financing.name_uk()) == folded)
+      .unwrap_or_else(|| FinancingType::Other(name.trim().to_string()))
+  }
+}
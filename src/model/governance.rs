@@ -0,0 +1,82 @@
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use super::lang::Lang;
+use super::fold_label;
+
+/// The body an institution answers to, from `university_governance_type_name`.
+///
+/// Only the most common governing bodies have variants. Regional administrations, other
+/// ministries and private owners are kept verbatim in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum GovernanceType {
+  MinistryOfEducation,       // Міністерство освіти і науки України
+  MinistryOfHealth,          // Міністерство охорони здоров'я України
+  MinistryOfInternalAffairs, // Міністерство внутрішніх справ України
+  MinistryOfDefence,         // Міністерство оборони України
+  Other(String),
+}
+
+impl fmt::Display for GovernanceType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name_en())
+  }
+}
+
+impl GovernanceType {
+  /// Every governing body with its own variant, in declaration order.
+  pub const ALL: [GovernanceType; 4] = [
+    GovernanceType::MinistryOfEducation,
+    GovernanceType::MinistryOfHealth,
+    GovernanceType::MinistryOfInternalAffairs,
+    GovernanceType::MinistryOfDefence,
+  ];
+
+  /// Returns the Ukrainian name of the governing body, or the name the registry sent for `Other`.
+  pub fn name_uk(&self) -> &str {
+    match self {
+      GovernanceType::MinistryOfEducation       => "Міністерство освіти і науки України",
+      GovernanceType::MinistryOfHealth          => "Міністерство охорони здоров'я України",
+      GovernanceType::MinistryOfInternalAffairs => "Міністерство внутрішніх справ України",
+      GovernanceType::MinistryOfDefence         => "Міністерство оборони України",
+      GovernanceType::Other(name) => name,
+    }
+  }
+
+  /// Returns the English name of the governing body. `Other` falls back to its Ukrainian name.
+  pub fn name_en(&self) -> &str {
+    match self {
+      GovernanceType::MinistryOfEducation       => "Ministry of Education and Science of Ukraine",
+      GovernanceType::MinistryOfHealth          => "Ministry of Health of Ukraine",
+      GovernanceType::MinistryOfInternalAffairs => "Ministry of Internal Affairs of Ukraine",
+      GovernanceType::MinistryOfDefence         => "Ministry of Defence of Ukraine",
+      GovernanceType::Other(name) => name,
+    }
+  }
+
+  /// Returns the name of the governing body in `lang`.
+  pub fn name(&self, lang: Lang) -> &str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+
+  /// Recognizes a governing body from `university_governance_type_name`, ignoring case,
+  /// spacing and apostrophe variants. Anything else becomes `Other`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::GovernanceType;
+  ///
+  /// assert_eq!(GovernanceType::from_name("Міністерство охорони здоров’я України"), GovernanceType::MinistryOfHealth);
+  /// assert_eq!(GovernanceType::from_name(" Київська міська рада "), GovernanceType::Other("Київська міська рада".to_string()));
+  /// ```
+  pub fn from_name(name: &str) -> GovernanceType {
+    let folded = fold_label(name);
+    GovernanceType::ALL
+      .into_iter()
+      .find(|body| fold_label(body.name_uk()) == folded)
+      .unwrap_or_else(|| GovernanceType::Other(name.trim().to_string()))
+  }
+}
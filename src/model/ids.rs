@@ -0,0 +1,87 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::error::Error;
+use super::de::StrOrNum;
+
+macro_rules! registry_id {
+  ($(#[$meta:meta])* $name:ident, $kind:literal) => {
+    $(#[$meta])*
+    ///
+    /// The registry encodes IDs as strings; this type reads them from either a JSON string or a
+    /// JSON number and writes them back as strings, so the wire format is unchanged.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct $name(pub i32);
+
+    impl fmt::Display for $name {
+      fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+      }
+    }
+
+    impl FromStr for $name {
+      type Err = Error;
+
+      fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim()
+          .parse()
+          .map($name)
+          .map_err(|_| Error::InvalidValue { kind: $kind, value: s.to_string() })
+      }
+    }
+
+    impl From<i32> for $name {
+      fn from(id: i32) -> Self {
+        $name(id)
+      }
+    }
+
+    impl From<$name> for i32 {
+      fn from(id: $name) -> Self {
+        id.0
+      }
+    }
+
+    impl Serialize for $name {
+      fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+      }
+    }
+
+    impl<'de> Deserialize<'de> for $name {
+      fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match StrOrNum::deserialize(deserializer)? {
+          StrOrNum::Num(n) => i32::try_from(n)
+            .map($name)
+            .map_err(|_| D::Error::custom(format!("invalid {}: {n}", $kind))),
+          StrOrNum::Str(s) => s.parse().map_err(D::Error::custom),
+        }
+      }
+    }
+  };
+}
+
+registry_id!(
+  /// The ID of a university (or another institution served by `/api/university`) in the registry.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::UniversityId;
+  ///
+  /// let id: UniversityId = "41".parse().unwrap();
+  /// assert_eq!(id, UniversityId(41));
+  /// assert_eq!(id.to_string(), "41");
+  /// assert_eq!(serde_json::to_string(&id).unwrap(), r#""41""#);
+  /// assert_eq!(serde_json::from_str::<UniversityId>("41").unwrap(), id);
+  /// ```
+  UniversityId,
+  "university ID"
+);
+
+registry_id!(
+  /// The ID of a secondary education institution in the registry.
+  InstitutionId,
+  "institution ID"
+);
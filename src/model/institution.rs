@@ -1,9 +1,32 @@
+use std::borrow::Cow;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+use std::str::FromStr;
 use serde::{Serialize, Deserialize};
+use crate::error::Error;
+use super::de;
+use super::lang::Lang;
+use super::ids::InstitutionId;
+use super::regions::Region;
+use super::{normalize_ws, profile_url, resolve_region, write_summary};
+use super::contact::{is_valid_email, normalize_phone, split_emails, ContactCard};
 
+/// Institution types accepted by the `/api/institutions` endpoint as its `ut` parameter.
+///
+/// | Code | Category                                               |
+/// |------|--------------------------------------------------------|
+/// | 3    | Заклади загальної середньої освіти (general secondary) |
+/// | 4    | Заклади позашкільної освіти (out-of-school)            |
+/// | 5    | Заклади дошкільної освіти (preschool)                  |
+///
+/// Boarding schools are not a separate category: they are general secondary institutions
+/// with [`Institution::is_internat`] set.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InstitutionCategory {
   GeneralSecondaryEducationInstitutions = 3, // Заклади загальної середньої освіти
+  OutOfSchoolEducationInstitutions      = 4, // Заклади позашкільної освіти
+  PreschoolEducationInstitutions        = 5, // Заклади дошкільної освіти
 }
 
 impl fmt::Display for InstitutionCategory {
@@ -12,11 +35,149 @@ impl fmt::Display for InstitutionCategory {
   }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl InstitutionCategory {
+  /// Every institution category, in order of its numeric code.
+  pub const ALL: [InstitutionCategory; 3] = [
+    InstitutionCategory::GeneralSecondaryEducationInstitutions,
+    InstitutionCategory::OutOfSchoolEducationInstitutions,
+    InstitutionCategory::PreschoolEducationInstitutions,
+  ];
+
+  /// Returns the Ukrainian name of the category, as used by the registry.
+  pub fn name_uk(&self) -> &'static str {
+    match self {
+      InstitutionCategory::GeneralSecondaryEducationInstitutions => "Заклади загальної середньої освіти",
+      InstitutionCategory::OutOfSchoolEducationInstitutions      => "Заклади позашкільної освіти",
+      InstitutionCategory::PreschoolEducationInstitutions        => "Заклади дошкільної освіти",
+    }
+  }
+
+  /// Returns the English name of the category.
+  pub fn name_en(&self) -> &'static str {
+    match self {
+      InstitutionCategory::GeneralSecondaryEducationInstitutions => "General secondary education institutions",
+      InstitutionCategory::OutOfSchoolEducationInstitutions      => "Out-of-school education institutions",
+      InstitutionCategory::PreschoolEducationInstitutions        => "Preschool education institutions",
+    }
+  }
+
+  /// Returns the name of the category in `lang`.
+  pub fn name(&self, lang: Lang) -> &'static str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+}
+
+/// Reconstructs an institution category from its numeric `ut` code.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::InstitutionCategory;
+///
+/// assert_eq!(InstitutionCategory::try_from(4).unwrap(), InstitutionCategory::OutOfSchoolEducationInstitutions);
+/// assert!(InstitutionCategory::try_from(1).is_err());
+/// ```
+impl TryFrom<i32> for InstitutionCategory {
+  type Error = Error;
+
+  fn try_from(code: i32) -> Result<Self, Self::Error> {
+    InstitutionCategory::ALL
+      .into_iter()
+      .find(|category| *category as i32 == code)
+      .ok_or_else(|| Error::InvalidValue { kind: "institution category code", value: code.to_string() })
+  }
+}
+
+/// Parses an institution category from a lowercase alias or its numeric `ut` code, for
+/// command-line arguments and configuration files.
+///
+/// | Alias                              | Category                                    |
+/// |------------------------------------|---------------------------------------------|
+/// | `secondary`, `school`              | `GeneralSecondaryEducationInstitutions` (3) |
+/// | `out-of-school`, `extracurricular` | `OutOfSchoolEducationInstitutions` (4)      |
+/// | `preschool`                        | `PreschoolEducationInstitutions` (5)        |
+///
+/// Case and surrounding whitespace are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::InstitutionCategory;
+///
+/// assert_eq!("school".parse::<InstitutionCategory>().unwrap(), InstitutionCategory::GeneralSecondaryEducationInstitutions);
+/// assert_eq!("5".parse::<InstitutionCategory>().unwrap(), InstitutionCategory::PreschoolEducationInstitutions);
+/// assert!("1".parse::<InstitutionCategory>().is_err());
+/// ```
+impl FromStr for InstitutionCategory {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || Error::InvalidValue {
+      kind: "institution category (expected secondary, out-of-school, preschool or a code: 3, 4, 5)",
+      value: s.to_string(),
+    };
+    let category = match s.trim().to_lowercase().as_str() {
+      "secondary" | "school" => InstitutionCategory::GeneralSecondaryEducationInstitutions,
+      "out-of-school" | "extracurricular" => InstitutionCategory::OutOfSchoolEducationInstitutions,
+      "preschool" => InstitutionCategory::PreschoolEducationInstitutions,
+      code => {
+        let code: i32 = code.parse().map_err(|_| invalid())?;
+        InstitutionCategory::try_from(code).map_err(|_| invalid())?
+      }
+    };
+    Ok(category)
+  }
+}
+
+/// A secondary, out-of-school or preschool education institution, as returned by
+/// `/api/school` and listed by `/api/institutions`.
+///
+/// # Serialization
+///
+/// Serializing an institution and reading the output back gives an equal record. Along the
+/// way `institution_id` is written as a string, the flags as JSON booleans, and a blank
+/// `parent_institution_id` or `approved_count` as `null`.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::Institution;
+/// use serde_json::json;
+///
+/// # let fixture = json!({
+/// #     "institution_name": "Ліцей № 1 Львівської міської ради", "institution_id": "137452", "is_checked": "1",
+/// #     "short_name": "Ліцей № 1", "state_name": "працює", "institution_type_name": "ліцей",
+/// #     "university_financing_type_name": "Комунальна", "koatuu_id": "4610136300", "region_name": "Львівська область",
+/// #     "koatuu_name": "м. Львів", "address": "вул. Городоцька, 36", "parent_institution_id": "",
+/// #     "governance_name": "Управління освіти Львівської міської ради", "phone": "0322610535", "fax": "",
+/// #     "email": "school1@ukr.net", "website": "", "boss": "Іваненко Ірина Петрівна", "support_name": "",
+/// #     "is_village": "0", "is_mountain": "0", "is_internat": "0", "approved_count": "900"
+/// # });
+/// let variants = [
+///     ("institution_id", vec![json!("137452"), json!(137452)]),
+///     ("parent_institution_id", vec![json!(""), json!(null), json!("137400")]),
+///     ("is_village", vec![json!("1"), json!(""), json!(0), json!(true), json!(null)]),
+///     ("approved_count", vec![json!("900"), json!(900), json!(""), json!(null)]),
+/// ];
+/// for (key, values) in variants {
+///     for value in values {
+///         let mut raw = fixture.clone();
+///         raw[key] = value;
+///         let institution: Institution = serde_json::from_value(raw).unwrap();
+///         let json = serde_json::to_string(&institution).unwrap();
+///         assert_eq!(serde_json::from_str::<Institution>(&json).unwrap(), institution);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Institution {
   pub institution_name: String,
-  pub institution_id: String,
-  pub is_checked: String,
+  pub institution_id: InstitutionId,
+  #[serde(default, deserialize_with = "de::flag")]
+  pub is_checked: bool,
   pub short_name: String,
   pub state_name: String,
   pub institution_type_name: String,
@@ -25,7 +186,8 @@ pub struct Institution {
   pub region_name: String,
   pub koatuu_name: String,
   pub address: String,
-  pub parent_institution_id: Option<String>,
+  #[serde(default, deserialize_with = "de::optional_id")]
+  pub parent_institution_id: Option<InstitutionId>,
   pub governance_name: String,
   pub phone: String,
   pub fax: String,
@@ -33,8 +195,162 @@ pub struct Institution {
   pub website: String,
   pub boss: String,
   pub support_name: String,
-  pub is_village: String,
-  pub is_mountain: String,
-  pub is_internat: String,
-  pub approved_count: Option<String>,
-}
\ No newline at end of file
+  #[serde(default, deserialize_with = "de::flag")]
+  pub is_village: bool,
+  #[serde(default, deserialize_with = "de::flag")]
+  pub is_mountain: bool,
+  #[serde(default, deserialize_with = "de::flag")]
+  pub is_internat: bool,
+  /// The approved number of pupils, if the registry has one. Sent as a string, a number or a blank.
+  #[serde(default, deserialize_with = "de::approved_count")]
+  pub approved_count: Option<u32>,
+  /// Keys sent by the registry that the model does not have a field for.
+  ///
+  /// Only present with the `extra-fields` feature. The keys are written back out on
+  /// serialization, so a record round-trips without losing data.
+  #[cfg(feature = "extra-fields")]
+  #[serde(flatten)]
+  pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Hashes only `institution_id`. Equality compares every field, as for
+/// [`UniversityBrief`](crate::UniversityBrief).
+impl Hash for Institution {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.institution_id.hash(state);
+  }
+}
+
+/// Summarizes the institution on one line: its Ukrainian name, short name, region and ID.
+impl fmt::Display for Institution {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write_summary(f, &self.institution_name, &self.short_name, &self.region_name, self.institution_id)
+  }
+}
+
+impl Institution {
+  /// Deserializes an institution from the JSON returned by `/api/school`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if the JSON is malformed or does not match the model.
+  pub fn from_json_str(json: &str) -> Result<Institution, Error> {
+    Ok(serde_json::from_str(json)?)
+  }
+
+  /// Deserializes an institution from a reader yielding the JSON returned by `/api/school`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if reading fails or the JSON does not match the model.
+  pub fn from_json_reader<R: Read>(reader: R) -> Result<Institution, Error> {
+    Ok(serde_json::from_reader(reader)?)
+  }
+
+  /// Returns the address of the institution's page on the public registry site,
+  /// `https://registry.edbo.gov.ua/institution/{id}/`.
+  pub fn profile_url(&self) -> String {
+    profile_url(crate::SCHOOL_PAGE, self.institution_id)
+  }
+
+  /// Returns the ID of the institution this one belongs to, such as the center a branch school
+  /// reports to.
+  ///
+  /// A missing or blank `parent_institution_id` yields `None`. A malformed one never gets
+  /// this far: it fails deserialization of the whole record.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Institution, InstitutionId};
+  ///
+  /// # let record = |parent: &str| serde_json::json!({
+  /// #     "institution_name": "", "institution_id": "137452", "short_name": "", "state_name": "",
+  /// #     "institution_type_name": "", "university_financing_type_name": "", "koatuu_id": "",
+  /// #     "region_name": "", "koatuu_name": "", "address": "", "parent_institution_id": parent,
+  /// #     "governance_name": "", "phone": "", "fax": "", "email": "", "website": "", "boss": "",
+  /// #     "support_name": ""
+  /// # });
+  /// let branch: Institution = serde_json::from_value(record("137400")).unwrap();
+  /// assert_eq!(branch.parent_id(), Some(InstitutionId(137400)));
+  ///
+  /// let standalone: Institution = serde_json::from_value(record(" ")).unwrap();
+  /// assert_eq!(standalone.parent_id(), None);
+  ///
+  /// assert!(serde_json::from_value::<Institution>(record("n/a")).is_err());
+  /// ```
+  pub fn parent_id(&self) -> Option<InstitutionId> {
+    self.parent_institution_id
+  }
+
+  /// Returns the region the institution is in.
+  ///
+  /// Like [`crate::University::region`], this trusts `region_name` first and falls back to the
+  /// leading two digits of the KOATUU code in `koatuu_id`.
+  pub fn region(&self) -> Option<Region> {
+    resolve_region(&self.region_name, &self.koatuu_id)
+  }
+
+  /// Returns `address` trimmed, with runs of whitespace inside it collapsed to one space.
+  /// See [`crate::University::address`].
+  pub fn address(&self) -> Cow<'_, str> {
+    normalize_ws(&self.address)
+  }
+
+  /// Returns `boss`, the head of the institution, with its whitespace normalized.
+  pub fn director(&self) -> Cow<'_, str> {
+    normalize_ws(&self.boss)
+  }
+
+  /// Returns `phone` in the canonical `+380XXXXXXXXX` form. See [`normalize_phone`].
+  pub fn phone_normalized(&self) -> Option<String> {
+    normalize_phone(&self.phone)
+  }
+
+  /// Returns `fax` in the canonical `+380XXXXXXXXX` form. See [`normalize_phone`].
+  pub fn fax_normalized(&self) -> Option<String> {
+    normalize_phone(&self.fax)
+  }
+
+  /// Returns the addresses listed in `email`. See [`split_emails`].
+  pub fn emails(&self) -> Vec<String> {
+    split_emails(&self.email)
+  }
+
+  /// Returns the addresses listed in `email` that are syntactically valid. See [`is_valid_email`].
+  pub fn valid_emails(&self) -> Vec<String> {
+    self.emails().into_iter().filter(|email| is_valid_email(email)).collect()
+  }
+
+  /// Returns the institution's name and contact details, cleaned up. See [`ContactCard`].
+  pub fn contact_card(&self) -> ContactCard {
+    ContactCard::from_fields(&self.institution_name, &self.phone, &self.email, &self.website, &self.address, &self.boss)
+  }
+
+  /// Returns `website` as a URL. See [`crate::University::website_url`] for the cleanup applied.
+  ///
+  /// Requires the `url` feature.
+  #[cfg(feature = "url")]
+  pub fn website_url(&self) -> Option<url::Url> {
+    super::website::website_url(&self.website)
+  }
+
+  /// Deserializes a list of institutions from the JSON returned by `/api/institutions`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if the JSON is malformed or does not match the model.
+  pub fn list_from_json_str(json: &str) -> Result<Vec<Institution>, Error> {
+    Ok(serde_json::from_str(json)?)
+  }
+
+  /// Deserializes a list of institutions from a reader yielding the JSON returned by
+  /// `/api/institutions`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if reading fails or the JSON does not match the model.
+  pub fn list_from_json_reader<R: Read>(reader: R) -> Result<Vec<Institution>, Error> {
+    Ok(serde_json::from_reader(reader)?)
+  }
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::error::Error;
+use super::regions::Region;
+
+/// A code from the Codifier of administrative-territorial units and territories of
+/// territorial communities (КАТОТТГ), split into its hierarchy.
+///
+/// A code is `UA` followed by 17 digits:
+///
+/// | Digits | Segment                                   |
+/// |--------|-------------------------------------------|
+/// | 1–2    | oblast, Crimea or a special-status city   |
+/// | 3–4    | raion                                     |
+/// | 5–7    | hromada (territorial community)           |
+/// | 8–10   | settlement                                |
+/// | 11–12  | city district                             |
+/// | 13–17  | unique object number                      |
+///
+/// A segment of all zeros means the code stops above that level, so `UA80000000000093317`
+/// (Kyiv) is a level 1 code and `UA46060250010015970` (Lviv) is a level 4 code.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::{Katottg, Region};
+///
+/// let code = Katottg::parse("UA46060250010015970")?;
+/// assert_eq!(code.oblast(), "46");
+/// assert_eq!(code.raion(), "06");
+/// assert_eq!(code.hromada(), "025");
+/// assert_eq!(code.settlement(), "001");
+/// assert_eq!(code.level(), 4);
+/// assert_eq!(code.region(), Some(Region::LvivOblast));
+/// # Ok::<(), libedbo::error::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Katottg {
+  code: String,
+}
+
+impl fmt::Display for Katottg {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.code)
+  }
+}
+
+impl FromStr for Katottg {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Katottg::parse(s)
+  }
+}
+
+impl Katottg {
+  /// Parses a KATOTTG code, ignoring surrounding whitespace. The `UA` prefix may be in any case.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidValue` if the code does not start with `UA`, is not followed by
+  /// exactly 17 ASCII digits, or has an all-zero oblast segment.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::Katottg;
+  ///
+  /// assert!(Katottg::parse("UA80000000000093317").is_ok());
+  /// assert!(Katottg::parse("8000000000").is_err());
+  /// assert!(Katottg::parse("UA8000000000009331").is_err());
+  /// ```
+  pub fn parse(code: &str) -> Result<Katottg, Error> {
+    let invalid = || Error::InvalidValue { kind: "KATOTTG code", value: code.to_string() };
+    let trimmed = code.trim();
+    let digits = match trimmed.get(..2) {
+      Some(prefix) if prefix.eq_ignore_ascii_case("UA") => &trimmed[2..],
+      _ => return Err(invalid()),
+    };
+    if digits.len() != 17 || !digits.bytes().all(|b| b.is_ascii_digit()) || &digits[..2] == "00" {
+      return Err(invalid());
+    }
+    Ok(Katottg { code: format!("UA{digits}") })
+  }
+
+  /// Returns the full code, e.g. `UA46060250010015970`.
+  pub fn as_str(&self) -> &str {
+    &self.code
+  }
+
+  /// Returns the two-digit oblast segment. It matches the [`Region`] code.
+  pub fn oblast(&self) -> &str {
+    &self.code[2..4]
+  }
+
+  /// Returns the two-digit raion segment, `"00"` for a level 1 code.
+  pub fn raion(&self) -> &str {
+    &self.code[4..6]
+  }
+
+  /// Returns the three-digit hromada segment, `"000"` above level 3.
+  pub fn hromada(&self) -> &str {
+    &self.code[6..9]
+  }
+
+  /// Returns the three-digit settlement segment, `"000"` above level 4.
+  pub fn settlement(&self) -> &str {
+    &self.code[9..12]
+  }
+
+  /// Returns the two-digit city district segment, `"00"` above level 5.
+  pub fn district(&self) -> &str {
+    &self.code[12..14]
+  }
+
+  /// Returns the five-digit unique object number.
+  pub fn object_id(&self) -> &str {
+    &self.code[14..19]
+  }
+
+  /// Returns the depth of the code in the hierarchy, from 1 (oblast) to 5 (city district).
+  pub fn level(&self) -> u8 {
+    let segments = [self.raion(), self.hromada(), self.settlement(), self.district()];
+    let deepest = segments.iter().rposition(|segment| segment.bytes().any(|b| b != b'0'));
+    deepest.map_or(1, |index| index as u8 + 2)
+  }
+
+  /// Returns the region of the oblast segment, or `None` if it is not a known region code.
+  pub fn region(&self) -> Option<Region> {
+    self.oblast().parse().ok()
+  }
+}
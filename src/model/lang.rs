@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+
+/// A language that names can be displayed in.
+///
+/// The registry has no language parameter: every endpoint answers in Ukrainian, and the only
+/// localized field it sends is the English university name (`university_name_en`). `Lang`
+/// selects between the registry's Ukrainian names and the English names this crate provides
+/// for regions and categories, and falls back to Ukrainian for the records themselves when
+/// no English name is available.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum Lang {
+  /// Ukrainian, the language of the registry.
+  #[default]
+  Uk,
+  /// English.
+  En,
+}
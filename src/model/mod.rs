@@ -1,7 +1,105 @@
 mod regions;
 mod university;
 mod institution;
+mod financing;
+mod university_type;
+mod governance;
+mod lang;
+mod katottg;
+mod contact;
+#[cfg(feature = "url")]
+mod website;
+mod de;
+mod ids;
 
 pub use regions::*;
 pub use university::*;
-pub use institution::*;
\ No newline at end of file
+pub use institution::*;
+pub use financing::*;
+pub use university_type::*;
+pub use governance::*;
+pub use lang::*;
+pub use katottg::*;
+pub use contact::*;
+pub use ids::*;
+
+/// Writes the one-line summary shared by the `Display` impls of the registry records:
+/// `name (short name) — region, id`, leaving out the parts the registry left blank.
+fn write_summary(f: &mut std::fmt::Formatter, name: &str, short_name: &str, region: &str, id: impl std::fmt::Display) -> std::fmt::Result {
+  write!(f, "{}", name.trim())?;
+  let short_name = short_name.trim();
+  if !short_name.is_empty() && short_name != name.trim() {
+    write!(f, " ({short_name})")?;
+  }
+  write!(f, " —")?;
+  let region = region.trim();
+  if !region.is_empty() {
+    write!(f, " {region},")?;
+  }
+  write!(f, " {id}")
+}
+
+/// Builds the address of a record's page on the public registry site, e.g.
+/// `https://registry.edbo.gov.ua/university/41/`.
+///
+/// The pages always live on the public site, whatever base URL the client was built with.
+fn profile_url(page: &str, id: impl std::fmt::Display) -> String {
+  format!("{}{page}/{id}/", crate::BASE_URL)
+}
+
+/// Trims `text` and collapses every run of whitespace inside it to a single space, borrowing
+/// when there is nothing to change.
+fn normalize_ws(text: &str) -> std::borrow::Cow<'_, str> {
+  let trimmed = text.trim();
+  if trimmed.is_empty() || trimmed.split(' ').all(|word| !word.is_empty() && !word.contains(char::is_whitespace)) {
+    std::borrow::Cow::Borrowed(trimmed)
+  } else {
+    std::borrow::Cow::Owned(trimmed.split_whitespace().collect::<Vec<_>>().join(" "))
+  }
+}
+
+/// Formats `count` with Ukrainian digit grouping: groups of three digits separated by a
+/// no-break space (U+00A0), so that a number is never split across lines.
+fn format_count(count: u32) -> String {
+  let digits = count.to_string();
+  let mut formatted = String::with_capacity(digits.len() + digits.len() / 3 * 2);
+  for (i, digit) in digits.chars().enumerate() {
+    if i > 0 && (digits.len() - i).is_multiple_of(3) {
+      formatted.push('\u{a0}');
+    }
+    formatted.push(digit);
+  }
+  formatted
+}
+
+/// Folds Cyrillic text for case- and accent-insensitive comparison: lowercase, with runs of
+/// whitespace collapsed to one space and apostrophes and stress marks (U+0301) removed.
+///
+/// Used both to look up the registry's classification names and to match names in searches,
+/// so that a name which matches a search also resolves to its classification.
+pub(crate) fn fold_label(name: &str) -> String {
+  normalize_ws(name)
+    .chars()
+    .filter(|c| !matches!(c, '\'' | '’' | 'ʼ' | '`' | '\u{0301}'))
+    .flat_map(char::to_lowercase)
+    .collect()
+}
+
+/// Resolves the region of a record from its region name, falling back to its administrative
+/// code when the name is blank or unknown.
+///
+/// The code may be a KATOTTG code or a ten-digit KOATUU code. Both start with the two-digit
+/// region code.
+fn resolve_region(name: &str, code: &str) -> Option<Region> {
+  Region::from_name(name).or_else(|| {
+    if let Ok(katottg) = Katottg::parse(code) {
+      return katottg.region();
+    }
+    let code = code.trim();
+    if code.len() == 10 && code.bytes().all(|b| b.is_ascii_digit()) {
+      code[..2].parse().ok()
+    } else {
+      None
+    }
+  })
+}
@@ -1,5 +1,9 @@
 use std::fmt;
+use std::str::FromStr;
 use serde::{Serialize, Deserialize};
+use crate::error::Error;
+use super::fold_label;
+use super::lang::Lang;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Region {
@@ -36,4 +40,383 @@ impl fmt::Display for Region {
   fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
     write!(f, "{}", *self as i32)
   }
-}
\ No newline at end of file
+}
+
+impl Region {
+  /// Every region, in order of its numeric code.
+  pub const ALL: [Region; 27] = [
+    Region::RepublicOfCrimea,
+    Region::VinnytsiaOblast,
+    Region::VolynOblast,
+    Region::DnipropetrovskOblast,
+    Region::DonetskOblast,
+    Region::ZhytomyrOblast,
+    Region::ZakarpattiaOblast,
+    Region::ZaporizhzhiaOblast,
+    Region::IvanoFrankivskOblast,
+    Region::KyivOblast,
+    Region::KirovohradOblast,
+    Region::LuhanskOblast,
+    Region::LvivOblast,
+    Region::MykolaivOblast,
+    Region::OdesaOblast,
+    Region::PoltavaOblast,
+    Region::RivneOblast,
+    Region::SumyOblast,
+    Region::TernopilOblast,
+    Region::KharkivOblast,
+    Region::KhersonOblast,
+    Region::KhmelnytskyiOblast,
+    Region::CherkasyOblast,
+    Region::ChernivtsiOblast,
+    Region::ChernihivOblast,
+    Region::KyivCity,
+    Region::SevastopolCity,
+  ];
+
+  /// Returns the official Ukrainian name of the region, e.g. "Львівська область".
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::Region;
+  ///
+  /// assert_eq!(Region::LvivOblast.name_uk(), "Львівська область");
+  /// ```
+  pub fn name_uk(&self) -> &'static str {
+    match self {
+      Region::RepublicOfCrimea     => "Автономна Республіка Крим",
+      Region::VinnytsiaOblast      => "Вінницька область",
+      Region::VolynOblast          => "Волинська область",
+      Region::DnipropetrovskOblast => "Дніпропетровська область",
+      Region::DonetskOblast        => "Донецька область",
+      Region::ZhytomyrOblast       => "Житомирська область",
+      Region::ZakarpattiaOblast    => "Закарпатська область",
+      Region::ZaporizhzhiaOblast   => "Запорізька область",
+      Region::IvanoFrankivskOblast => "Івано-Франківська область",
+      Region::KyivOblast           => "Київська область",
+      Region::KirovohradOblast     => "Кіровоградська область",
+      Region::LuhanskOblast        => "Луганська область",
+      Region::LvivOblast           => "Львівська область",
+      Region::MykolaivOblast       => "Миколаївська область",
+      Region::OdesaOblast          => "Одеська область",
+      Region::PoltavaOblast        => "Полтавська область",
+      Region::RivneOblast          => "Рівненська область",
+      Region::SumyOblast           => "Сумська область",
+      Region::TernopilOblast       => "Тернопільська область",
+      Region::KharkivOblast        => "Харківська область",
+      Region::KhersonOblast        => "Херсонська область",
+      Region::KhmelnytskyiOblast   => "Хмельницька область",
+      Region::CherkasyOblast       => "Черкаська область",
+      Region::ChernivtsiOblast     => "Чернівецька область",
+      Region::ChernihivOblast      => "Чернігівська область",
+      Region::KyivCity             => "м. Київ",
+      Region::SevastopolCity       => "м. Севастополь",
+    }
+  }
+
+  /// Returns the English name of the region, e.g. "Lviv Oblast".
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::Region;
+  ///
+  /// assert_eq!(Region::LvivOblast.name_en(), "Lviv Oblast");
+  /// ```
+  pub fn name_en(&self) -> &'static str {
+    match self {
+      Region::RepublicOfCrimea     => "Autonomous Republic of Crimea",
+      Region::VinnytsiaOblast      => "Vinnytsia Oblast",
+      Region::VolynOblast          => "Volyn Oblast",
+      Region::DnipropetrovskOblast => "Dnipropetrovsk Oblast",
+      Region::DonetskOblast        => "Donetsk Oblast",
+      Region::ZhytomyrOblast       => "Zhytomyr Oblast",
+      Region::ZakarpattiaOblast    => "Zakarpattia Oblast",
+      Region::ZaporizhzhiaOblast   => "Zaporizhzhia Oblast",
+      Region::IvanoFrankivskOblast => "Ivano-Frankivsk Oblast",
+      Region::KyivOblast           => "Kyiv Oblast",
+      Region::KirovohradOblast     => "Kirovohrad Oblast",
+      Region::LuhanskOblast        => "Luhansk Oblast",
+      Region::LvivOblast           => "Lviv Oblast",
+      Region::MykolaivOblast       => "Mykolaiv Oblast",
+      Region::OdesaOblast          => "Odesa Oblast",
+      Region::PoltavaOblast        => "Poltava Oblast",
+      Region::RivneOblast          => "Rivne Oblast",
+      Region::SumyOblast           => "Sumy Oblast",
+      Region::TernopilOblast       => "Ternopil Oblast",
+      Region::KharkivOblast        => "Kharkiv Oblast",
+      Region::KhersonOblast        => "Kherson Oblast",
+      Region::KhmelnytskyiOblast   => "Khmelnytskyi Oblast",
+      Region::CherkasyOblast       => "Cherkasy Oblast",
+      Region::ChernivtsiOblast     => "Chernivtsi Oblast",
+      Region::ChernihivOblast      => "Chernihiv Oblast",
+      Region::KyivCity             => "Kyiv City",
+      Region::SevastopolCity       => "Sevastopol City",
+    }
+  }
+
+  /// Returns the name of the region in `lang`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Region, Lang};
+  ///
+  /// assert_eq!(Region::LvivOblast.name(Lang::Uk), "Львівська область");
+  /// assert_eq!(Region::LvivOblast.name(Lang::En), "Lviv Oblast");
+  /// ```
+  pub fn name(&self, lang: Lang) -> &'static str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+
+  /// Looks up a region by the name the registry uses for it, such as `region_name_u`.
+  ///
+  /// Matches the Ukrainian or English name, ignoring case and extra whitespace. The
+  /// abbreviation "обл." is accepted for "область", and the "м." prefix of Kyiv and
+  /// Sevastopol may be left out or written without a space.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::Region;
+  ///
+  /// assert_eq!(Region::from_name("Львівська область"), Some(Region::LvivOblast));
+  /// assert_eq!(Region::from_name(" львівська обл. "), Some(Region::LvivOblast));
+  /// assert_eq!(Region::from_name("м.Київ"), Some(Region::KyivCity));
+  /// assert_eq!(Region::from_name("Kyiv City"), Some(Region::KyivCity));
+  /// assert_eq!(Region::from_name(""), None);
+  /// ```
+  pub fn from_name(name: &str) -> Option<Region> {
+    let name = region_key(name);
+    if name.is_empty() {
+      return None;
+    }
+    Region::ALL
+      .into_iter()
+      .find(|region| region_key(region.name_uk()) == name || region_key(region.name_en()) == name)
+  }
+
+  /// Returns the part of Ukraine the region belongs to.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{MacroRegion, Region};
+  ///
+  /// assert_eq!(Region::LvivOblast.macro_region(), MacroRegion::West);
+  /// assert_eq!(Region::KyivCity.macro_region(), MacroRegion::North);
+  /// ```
+  pub const fn macro_region(&self) -> MacroRegion {
+    match self {
+      Region::VolynOblast
+      | Region::ZakarpattiaOblast
+      | Region::IvanoFrankivskOblast
+      | Region::LvivOblast
+      | Region::RivneOblast
+      | Region::TernopilOblast
+      | Region::KhmelnytskyiOblast
+      | Region::ChernivtsiOblast => MacroRegion::West,
+      Region::ZhytomyrOblast
+      | Region::KyivOblast
+      | Region::SumyOblast
+      | Region::ChernihivOblast
+      | Region::KyivCity => MacroRegion::North,
+      Region::VinnytsiaOblast
+      | Region::DnipropetrovskOblast
+      | Region::KirovohradOblast
+      | Region::PoltavaOblast
+      | Region::CherkasyOblast => MacroRegion::Center,
+      Region::DonetskOblast
+      | Region::LuhanskOblast
+      | Region::KharkivOblast => MacroRegion::East,
+      Region::RepublicOfCrimea
+      | Region::ZaporizhzhiaOblast
+      | Region::MykolaivOblast
+      | Region::OdesaOblast
+      | Region::KhersonOblast
+      | Region::SevastopolCity => MacroRegion::South,
+    }
+  }
+}
+
+/// A group of neighbouring regions, for grouping regions in a UI or aggregating by part of
+/// the country.
+///
+/// The grouping follows the usual five-way division of Ukraine:
+///
+/// * West - Volyn, Zakarpattia, Ivano-Frankivsk, Lviv, Rivne, Ternopil, Khmelnytskyi and
+///   Chernivtsi oblasts
+/// * North - Zhytomyr, Kyiv, Sumy and Chernihiv oblasts, and the city of Kyiv
+/// * Center - Vinnytsia, Dnipropetrovsk, Kirovohrad, Poltava and Cherkasy oblasts
+/// * East - Donetsk, Luhansk and Kharkiv oblasts
+/// * South - Zaporizhzhia, Mykolaiv, Odesa and Kherson oblasts, the Autonomous Republic of
+///   Crimea and the city of Sevastopol
+///
+/// Every [`Region`] belongs to exactly one group.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::{MacroRegion, Region};
+///
+/// for group in MacroRegion::ALL {
+///     assert!(group.regions().iter().all(|region| region.macro_region() == group));
+/// }
+/// let total: usize = MacroRegion::ALL.iter().map(|group| group.regions().len()).sum();
+/// assert_eq!(total, Region::ALL.len());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MacroRegion {
+  West,
+  North,
+  Center,
+  East,
+  South,
+}
+
+impl MacroRegion {
+  /// Every group, from west to south.
+  pub const ALL: [MacroRegion; 5] = [
+    MacroRegion::West,
+    MacroRegion::North,
+    MacroRegion::Center,
+    MacroRegion::East,
+    MacroRegion::South,
+  ];
+
+  /// Returns the regions in the group, in order of their numeric code.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{MacroRegion, Region};
+  ///
+  /// assert_eq!(MacroRegion::East.regions(), [Region::DonetskOblast, Region::LuhanskOblast, Region::KharkivOblast]);
+  /// ```
+  pub const fn regions(&self) -> &'static [Region] {
+    match self {
+      MacroRegion::West => &[
+        Region::VolynOblast,
+        Region::ZakarpattiaOblast,
+        Region::IvanoFrankivskOblast,
+        Region::LvivOblast,
+        Region::RivneOblast,
+        Region::TernopilOblast,
+        Region::KhmelnytskyiOblast,
+        Region::ChernivtsiOblast,
+      ],
+      MacroRegion::North => &[
+        Region::ZhytomyrOblast,
+        Region::KyivOblast,
+        Region::SumyOblast,
+        Region::ChernihivOblast,
+        Region::KyivCity,
+      ],
+      MacroRegion::Center => &[
+        Region::VinnytsiaOblast,
+        Region::DnipropetrovskOblast,
+        Region::KirovohradOblast,
+        Region::PoltavaOblast,
+        Region::CherkasyOblast,
+      ],
+      MacroRegion::East => &[Region::DonetskOblast, Region::LuhanskOblast, Region::KharkivOblast],
+      MacroRegion::South => &[
+        Region::RepublicOfCrimea,
+        Region::ZaporizhzhiaOblast,
+        Region::MykolaivOblast,
+        Region::OdesaOblast,
+        Region::KhersonOblast,
+        Region::SevastopolCity,
+      ],
+    }
+  }
+
+  /// Returns the Ukrainian name of the group, e.g. "Захід".
+  pub fn name_uk(&self) -> &'static str {
+    match self {
+      MacroRegion::West   => "Захід",
+      MacroRegion::North  => "Північ",
+      MacroRegion::Center => "Центр",
+      MacroRegion::East   => "Схід",
+      MacroRegion::South  => "Південь",
+    }
+  }
+
+  /// Returns the English name of the group, e.g. "West".
+  pub fn name_en(&self) -> &'static str {
+    match self {
+      MacroRegion::West   => "West",
+      MacroRegion::North  => "North",
+      MacroRegion::Center => "Center",
+      MacroRegion::East   => "East",
+      MacroRegion::South  => "South",
+    }
+  }
+
+  /// Returns the name of the group in `lang`.
+  pub fn name(&self, lang: Lang) -> &'static str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+}
+
+/// Reconstructs a region from its numeric `lc` code.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::Region;
+///
+/// assert_eq!(Region::try_from(80).unwrap(), Region::KyivCity);
+/// assert!(Region::try_from(2).is_err());
+/// ```
+impl TryFrom<i32> for Region {
+  type Error = Error;
+
+  fn try_from(code: i32) -> Result<Self, Self::Error> {
+    Region::ALL
+      .into_iter()
+      .find(|region| *region as i32 == code)
+      .ok_or_else(|| Error::InvalidValue { kind: "region code", value: code.to_string() })
+  }
+}
+
+/// Parses a region from its numeric `lc` code, as printed by `Display`.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::Region;
+///
+/// assert_eq!("46".parse::<Region>().unwrap(), Region::LvivOblast);
+/// assert!("Lviv".parse::<Region>().is_err());
+/// ```
+impl FromStr for Region {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let code = s
+      .trim()
+      .parse::<i32>()
+      .map_err(|_| Error::InvalidValue { kind: "region code", value: s.to_string() })?;
+    Region::try_from(code)
+  }
+}
+
+/// Folds a region name for [`Region::from_name`]: expands "обл." and drops the "м." prefix.
+fn region_key(name: &str) -> String {
+  let mut name = fold_label(name);
+  if let Some(oblast) = name.strip_suffix(" обл.") {
+    name = format!("{oblast} область");
+  }
+  match name.strip_prefix("м.") {
+    Some(city) => city.trim_start().to_string(),
+    None => name,
+  }
+}
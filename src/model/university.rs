@@ -1,5 +1,19 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::io::Read;
+use std::str::FromStr;
 use serde::{Serialize, Deserialize};
+use crate::error::Error;
+use super::lang::Lang;
+use super::{FinancingType, GovernanceType, UniversityType};
+use super::de;
+use super::ids::UniversityId;
+use super::katottg::Katottg;
+use super::regions::Region;
+use super::{format_count, normalize_ws, profile_url, resolve_region, write_summary};
+use super::contact::{is_valid_email, normalize_phone, split_emails, ContactCard};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum UniversityCategory {
@@ -16,61 +30,366 @@ impl fmt::Display for UniversityCategory {
   }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl UniversityCategory {
+  /// Every university category, in declaration order.
+  pub const ALL: [UniversityCategory; 5] = [
+    UniversityCategory::HigherEducationInstitutions,
+    UniversityCategory::VocationalEducationInstitutions,
+    UniversityCategory::SpecializedPreHigherEducationInstitutions,
+    UniversityCategory::ScientificInstitutes,
+    UniversityCategory::PostgraduateEducationInstitutions,
+  ];
+
+  /// Returns the Ukrainian name of the category, as used by the registry.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::UniversityCategory;
+  ///
+  /// assert_eq!(UniversityCategory::HigherEducationInstitutions.name_uk(), "Заклади вищої освіти");
+  /// ```
+  pub fn name_uk(&self) -> &'static str {
+    match self {
+      UniversityCategory::HigherEducationInstitutions               => "Заклади вищої освіти",
+      UniversityCategory::VocationalEducationInstitutions           => "Заклади професійної (професійно-технічної) освіти",
+      UniversityCategory::SpecializedPreHigherEducationInstitutions => "Заклади фахової передвищої освіти",
+      UniversityCategory::ScientificInstitutes                      => "Наукові інститути (установи)",
+      UniversityCategory::PostgraduateEducationInstitutions         => "Заклади післядипломної освіти",
+    }
+  }
+
+  /// Returns the English name of the category.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::UniversityCategory;
+  ///
+  /// assert_eq!(UniversityCategory::HigherEducationInstitutions.name_en(), "Higher education institutions");
+  /// ```
+  pub fn name_en(&self) -> &'static str {
+    match self {
+      UniversityCategory::HigherEducationInstitutions               => "Higher education institutions",
+      UniversityCategory::VocationalEducationInstitutions           => "Vocational (vocational-technical) education institutions",
+      UniversityCategory::SpecializedPreHigherEducationInstitutions => "Specialized pre-higher education institutions",
+      UniversityCategory::ScientificInstitutes                      => "Scientific institutes (institutions)",
+      UniversityCategory::PostgraduateEducationInstitutions         => "Postgraduate education institutions",
+    }
+  }
+
+  /// Returns the name of the category in `lang`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{UniversityCategory, Lang};
+  ///
+  /// assert_eq!(UniversityCategory::HigherEducationInstitutions.name(Lang::Uk), "Заклади вищої освіти");
+  /// assert_eq!(UniversityCategory::HigherEducationInstitutions.name(Lang::En), "Higher education institutions");
+  /// ```
+  pub fn name(&self, lang: Lang) -> &'static str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+}
+
+/// Reconstructs a university category from its numeric `ut` code.
+///
+/// The codes are not sequential: 1, 2, 8, 9 and 10 are the only valid values.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::UniversityCategory;
+///
+/// assert_eq!(UniversityCategory::try_from(2).unwrap(), UniversityCategory::VocationalEducationInstitutions);
+/// assert_eq!(UniversityCategory::try_from(9).unwrap(), UniversityCategory::SpecializedPreHigherEducationInstitutions);
+/// assert!(UniversityCategory::try_from(3).is_err());
+/// ```
+impl TryFrom<i32> for UniversityCategory {
+  type Error = Error;
+
+  fn try_from(code: i32) -> Result<Self, Self::Error> {
+    UniversityCategory::ALL
+      .into_iter()
+      .find(|category| *category as i32 == code)
+      .ok_or_else(|| Error::InvalidValue { kind: "university category code", value: code.to_string() })
+  }
+}
+
+/// Parses a university category from a lowercase alias or its numeric `ut` code, for
+/// command-line arguments and configuration files.
+///
+/// | Alias                     | Category                                        |
+/// |---------------------------|-------------------------------------------------|
+/// | `hei`, `higher`           | `HigherEducationInstitutions` (1)               |
+/// | `vocational`              | `VocationalEducationInstitutions` (2)           |
+/// | `research`, `scientific`  | `ScientificInstitutes` (8)                      |
+/// | `pre-higher`, `prehigher` | `SpecializedPreHigherEducationInstitutions` (9) |
+/// | `postgraduate`            | `PostgraduateEducationInstitutions` (10)        |
+///
+/// Case and surrounding whitespace are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::UniversityCategory;
+///
+/// assert_eq!("hei".parse::<UniversityCategory>().unwrap(), UniversityCategory::HigherEducationInstitutions);
+/// assert_eq!(" Vocational ".parse::<UniversityCategory>().unwrap(), UniversityCategory::VocationalEducationInstitutions);
+/// assert_eq!("9".parse::<UniversityCategory>().unwrap(), UniversityCategory::SpecializedPreHigherEducationInstitutions);
+///
+/// let err = "college".parse::<UniversityCategory>().unwrap_err();
+/// assert!(err.to_string().contains("hei, vocational"));
+/// ```
+impl FromStr for UniversityCategory {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    let invalid = || Error::InvalidValue {
+      kind: "university category (expected hei, vocational, research, pre-higher, postgraduate or a code: 1, 2, 8, 9, 10)",
+      value: s.to_string(),
+    };
+    let category = match s.trim().to_lowercase().as_str() {
+      "hei" | "higher" => UniversityCategory::HigherEducationInstitutions,
+      "vocational" => UniversityCategory::VocationalEducationInstitutions,
+      "research" | "scientific" => UniversityCategory::ScientificInstitutes,
+      "pre-higher" | "prehigher" => UniversityCategory::SpecializedPreHigherEducationInstitutions,
+      "postgraduate" => UniversityCategory::PostgraduateEducationInstitutions,
+      code => {
+        let code: i32 = code.parse().map_err(|_| invalid())?;
+        UniversityCategory::try_from(code).map_err(|_| invalid())?
+      }
+    };
+    Ok(category)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UniversityBranch {
   pub university_name: String,
-  pub university_id: String,
+  pub university_id: UniversityId,
   pub region_name: String,
   pub katottgcodeu: String,
   pub katottg_name: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Hashes only `university_id`, like [`UniversityBrief`].
+impl Hash for UniversityBranch {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.university_id.hash(state);
+  }
+}
+
+/// A licensed speciality of a university, with its licensed enrollment capacity.
+///
+/// The count fields are read leniently: the registry sends them as strings, numbers or blanks,
+/// and blanks are treated as 0. Text fields accept numbers too, and a missing or `null` value
+/// is read as an empty string.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::SpecialityLicense;
+///
+/// let json = r#"{
+///     "qualification_group_name": "Бакалавр", "speciality_code": "121",
+///     "speciality_name": "Інженерія програмного забезпечення", "specialization_name": "",
+///     "all_count": "15", "all_term_count": "", "full_time_count": 15, "part_time_count": "",
+///     "evening_count": null, "certificate": "", "certificate_expired": null, "license_description": ""
+/// }"#;
+/// let license: SpecialityLicense = serde_json::from_str(json).unwrap();
+/// assert_eq!(license.all_count, 15);
+/// assert_eq!(license.full_time_count, 15);
+/// assert_eq!(license.part_time_count, 0);
+/// assert_eq!(license.evening_count, 0);
+///
+/// let json = r#"{"speciality_code": 121, "all_count": 15, "all_term_count": 60}"#;
+/// let license: SpecialityLicense = serde_json::from_str(json).unwrap();
+/// assert_eq!(license.speciality_code, "121");
+/// assert_eq!(license.all_term_count, "60");
+/// assert_eq!(license.certificate, "");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpecialityLicense {
+  #[serde(default, deserialize_with = "de::text")]
   pub qualification_group_name: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub speciality_code: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub speciality_name: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub specialization_name: String,
-  pub all_count: String,
+  #[serde(default, deserialize_with = "de::count")]
+  pub all_count: u32,
+  #[serde(default, deserialize_with = "de::text")]
   pub all_term_count: String,
-  pub full_time_count: String,
-  pub part_time_count: String,
-  pub evening_count: String,
+  #[serde(default, deserialize_with = "de::count")]
+  pub full_time_count: u32,
+  #[serde(default, deserialize_with = "de::count")]
+  pub part_time_count: u32,
+  #[serde(default, deserialize_with = "de::count")]
+  pub evening_count: u32,
+  #[serde(default, deserialize_with = "de::text")]
   pub certificate: String,
   pub certificate_expired: Option<String>,
+  #[serde(default, deserialize_with = "de::text")]
   pub license_description: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl SpecialityLicense {
+  /// Returns `all_count` formatted for display in Ukrainian, with digits grouped in threes by
+  /// a no-break space.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::SpecialityLicense;
+  ///
+  /// let license: SpecialityLicense = serde_json::from_str(r#"{"all_count": "12500"}"#).unwrap();
+  /// assert_eq!(license.formatted_all_count(), "12\u{a0}500");
+  ///
+  /// let license: SpecialityLicense = serde_json::from_str(r#"{"all_count": 1234567}"#).unwrap();
+  /// assert_eq!(license.formatted_all_count(), "1\u{a0}234\u{a0}567");
+  ///
+  /// let license: SpecialityLicense = serde_json::from_str(r#"{"all_count": 150}"#).unwrap();
+  /// assert_eq!(license.formatted_all_count(), "150");
+  /// ```
+  pub fn formatted_all_count(&self) -> String {
+    format_count(self.all_count)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ProfessionLicense {
+  #[serde(default, deserialize_with = "de::text")]
   pub professions: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub license_count: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub accreditation: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub accreditation_expired: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Educator {
+  #[serde(default, deserialize_with = "de::text")]
   pub qualification_group_name: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub speciality_code: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub speciality_name: String,
+  #[serde(default, deserialize_with = "de::text")]
   pub specialization_name: String,
-  pub full_time_count: String,
-  pub part_time_count: String,
-  pub external_count: String,
-  pub evening_count: String,
-  pub distance_count: String,
+  #[serde(default, deserialize_with = "de::count")]
+  pub full_time_count: u32,
+  #[serde(default, deserialize_with = "de::count")]
+  pub part_time_count: u32,
+  #[serde(default, deserialize_with = "de::count")]
+  pub external_count: u32,
+  #[serde(default, deserialize_with = "de::count")]
+  pub evening_count: u32,
+  #[serde(default, deserialize_with = "de::count")]
+  pub distance_count: u32,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Educator {
+  /// Returns the sum of the full-time, part-time, external, evening and distance counts,
+  /// saturating at `u32::MAX`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::Educator;
+  ///
+  /// let json = r#"{"speciality_code": "121", "full_time_count": "40", "part_time_count": 5, "distance_count": ""}"#;
+  /// let educator: Educator = serde_json::from_str(json).unwrap();
+  /// assert_eq!(educator.total(), 45);
+  /// ```
+  pub fn total(&self) -> u32 {
+    [self.part_time_count, self.external_count, self.evening_count, self.distance_count]
+      .into_iter()
+      .fold(self.full_time_count, u32::saturating_add)
+  }
+}
+
+/// The full record of a university, as returned by `/api/university`.
+///
+/// # Serialization
+///
+/// Serializing a record and deserializing the output gives back an equal record, with any
+/// combination of the `chrono` and `extra-fields` features. The output is not always the JSON
+/// the registry sent, because lenient fields are normalized when they are read:
+///
+/// - IDs are written as strings, and a blank `university_parent_id` as `null`;
+/// - `is_from_crimea` is written as a JSON boolean rather than `"1"` or `"0"`;
+/// - a blank `registration_year` is written as `null`;
+/// - blank counts in licenses and educators are written as `0`, and numbers in their text
+///   fields as strings;
+/// - with `chrono`, `close_date` is written as `YYYY-MM-DD`, so a time the registry appended
+///   to it is lost.
+///
+/// # Examples
+///
+/// Round-tripping a captured record with each lenient field in every form the registry sends:
+///
+/// ```rust
+/// use libedbo::University;
+/// use serde_json::json;
+///
+/// # let fixture = json!({
+/// #     "university_name": "Київський національний університет імені Тараса Шевченка", "university_id": "41",
+/// #     "university_parent_id": "", "university_short_name": "КНУ імені Тараса Шевченка",
+/// #     "university_name_en": "Taras Shevchenko National University of Kyiv", "is_from_crimea": "0",
+/// #     "registration_year": "1834", "university_type_name": "Університет",
+/// #     "university_financing_type_name": "Державна", "university_governance_type_name": "Міністерство освіти і науки України",
+/// #     "post_index_u": "01601", "katottgcodeu": "UA80000000000093317", "katottg_name_u": "м. Київ",
+/// #     "region_name_u": "м. Київ", "university_address_u": "вул. Володимирська, буд. 60",
+/// #     "university_phone": "(044) 239-33-33", "university_email": "office.chief@univ.kiev.ua",
+/// #     "university_site": "http://www.univ.kiev.ua", "university_director_post": "Ректор",
+/// #     "university_director_fio": "Бугров Володимир Анатолійович", "close_date": null,
+/// #     "branches": [{"university_name": "Фаховий коледж", "university_id": "2751", "region_name": "м. Київ",
+/// #         "katottgcodeu": "UA80000000000093317", "katottg_name": "м. Київ"}],
+/// #     "facultets": ["Історичний факультет", "Юридичний факультет"],
+/// #     "speciality_licenses": [], "profession_licenses": [{"professions": "", "license_count": 25,
+/// #         "accreditation": "", "accreditation_expired": ""}], "educators": []
+/// # });
+/// let variants = [
+///     ("university_id", vec![json!("41"), json!(41)]),
+///     ("university_parent_id", vec![json!(""), json!(null), json!("40"), json!(40)]),
+///     ("is_from_crimea", vec![json!("1"), json!("0"), json!(""), json!(1), json!(true), json!(null)]),
+///     ("registration_year", vec![json!("1834"), json!(1834), json!(""), json!(null)]),
+///     ("close_date", vec![json!(null), json!(""), json!("2020-09-01"), json!("2020-09-01 00:00:00")]),
+///     ("speciality_licenses", vec![json!([{"speciality_code": 121, "all_count": "", "full_time_count": "15", "certificate_expired": null}])]),
+///     ("educators", vec![json!([{"speciality_code": "121", "full_time_count": "", "part_time_count": 5}])]),
+///     ("new_registry_field", vec![json!({"nested": [1, null]})]),
+/// ];
+/// for (key, values) in variants {
+///     for value in values {
+///         let mut raw = fixture.clone();
+///         raw[key] = value;
+///         let university: University = serde_json::from_value(raw).unwrap();
+///         let json = serde_json::to_string(&university).unwrap();
+///         assert_eq!(serde_json::from_str::<University>(&json).unwrap(), university);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct University {
   pub university_name: String,
-  pub university_id: String,
-  pub university_parent_id: Option<String>,
+  pub university_id: UniversityId,
+  #[serde(default, deserialize_with = "de::optional_id")]
+  pub university_parent_id: Option<UniversityId>,
   pub university_short_name: String,
   pub university_name_en: String,
-  pub is_from_crimea: String,
-  pub registration_year: String,
+  #[serde(default, deserialize_with = "de::flag")]
+  pub is_from_crimea: bool,
+  #[serde(default, deserialize_with = "de::registration_year")]
+  pub registration_year: Option<u16>,
   pub university_type_name: String,
   pub university_financing_type_name: String,
   pub university_governance_type_name: String,
@@ -84,23 +403,405 @@ pub struct University {
   pub university_site: String,
   pub university_director_post: String,
   pub university_director_fio: String,
+  /// The date the institution was closed, if it has been.
+  ///
+  /// With the `chrono` feature enabled this is parsed from the registry's `YYYY-MM-DD` format.
+  #[cfg(feature = "chrono")]
+  #[serde(default, deserialize_with = "de::close_date")]
+  pub close_date: Option<chrono::NaiveDate>,
+  /// The date the institution was closed, if it has been, as sent by the registry.
+  #[cfg(not(feature = "chrono"))]
   pub close_date: Option<String>,
   pub branches: Vec<UniversityBranch>,
   pub facultets: Vec<String>,
   pub speciality_licenses: Vec<SpecialityLicense>,
   pub profession_licenses: Vec<ProfessionLicense>,
   pub educators: Vec<Educator>,
+  /// Keys sent by the registry that the model does not have a field for.
+  ///
+  /// Only present with the `extra-fields` feature. The keys are written back out on
+  /// serialization, so a record round-trips without losing data.
+  #[cfg(feature = "extra-fields")]
+  #[serde(flatten)]
+  pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Hashes only `university_id`, like [`UniversityBrief`].
+impl Hash for University {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.university_id.hash(state);
+  }
+}
+
+/// Summarizes the university on one line: its Ukrainian name, short name, region and ID.
+///
+/// This matches the `Display` output of [`UniversityBrief`].
+impl fmt::Display for University {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write_summary(f, &self.university_name, &self.university_short_name, &self.region_name_u, self.university_id)
+  }
+}
+
+impl UniversityBranch {
+  /// Returns the branch's university ID.
+  pub fn id(&self) -> UniversityId {
+    self.university_id
+  }
+}
+
+impl University {
+  /// Deserializes a university from the JSON returned by `/api/university`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if the JSON is malformed or does not match the model.
+  pub fn from_json_str(json: &str) -> Result<University, Error> {
+    Ok(serde_json::from_str(json)?)
+  }
+
+  /// Deserializes a university from a reader yielding the JSON returned by `/api/university`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if reading fails or the JSON does not match the model.
+  pub fn from_json_reader<R: Read>(reader: R) -> Result<University, Error> {
+    Ok(serde_json::from_reader(reader)?)
+  }
+
+  /// Returns the university's name in `lang`.
+  ///
+  /// The English name is `university_name_en`, which the registry leaves blank for many
+  /// institutions; the Ukrainian name is returned in that case.
+  pub fn name(&self, lang: Lang) -> &str {
+    match lang {
+      Lang::En if !self.university_name_en.trim().is_empty() => &self.university_name_en,
+      _ => &self.university_name,
+    }
+  }
+
+  /// Classifies `university_type_name`. See [`UniversityType::from_name`].
+  pub fn university_type(&self) -> UniversityType {
+    UniversityType::from_name(&self.university_type_name)
+  }
+
+  /// Classifies `university_financing_type_name`. See [`FinancingType::from_name`].
+  pub fn financing_type(&self) -> FinancingType {
+    FinancingType::from_name(&self.university_financing_type_name)
+  }
+
+  /// Classifies `university_governance_type_name`. See [`GovernanceType::from_name`].
+  pub fn governance_type(&self) -> GovernanceType {
+    GovernanceType::from_name(&self.university_governance_type_name)
+  }
+
+  /// Returns true if the registry lists a close date for the university.
+  ///
+  /// A blank `close_date` counts as open.
+  pub fn is_closed(&self) -> bool {
+    is_closed(&self.close_date)
+  }
+
+  /// Returns true if the university was closed before `date`.
+  ///
+  /// Requires the `chrono` feature.
+  #[cfg(feature = "chrono")]
+  pub fn closed_before(&self, date: chrono::NaiveDate) -> bool {
+    self.close_date.is_some_and(|closed| closed < date)
+  }
+
+  /// Returns the address of the university's page on the public registry site,
+  /// `https://registry.edbo.gov.ua/university/{id}/`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # let university: libedbo::University = serde_json::from_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/university_min.json"))).unwrap();
+  /// assert_eq!(university.profile_url(), "https://registry.edbo.gov.ua/university/41/");
+  /// ```
+  pub fn profile_url(&self) -> String {
+    profile_url(crate::UNIVERSITY_PAGE, self.university_id)
+  }
+
+  /// Returns the ID of the parent university, if this one is a branch.
+  ///
+  /// A missing or blank `university_parent_id` yields `None`; a malformed one is rejected when
+  /// the record is deserialized.
+  pub fn parent_id(&self) -> Option<UniversityId> {
+    self.university_parent_id
+  }
+
+  /// Parses `katottgcodeu` into its administrative hierarchy.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidValue` if the code is blank or malformed. See [`Katottg::parse`].
+  pub fn katottg(&self) -> Result<Katottg, Error> {
+    Katottg::parse(&self.katottgcodeu)
+  }
+
+  /// Returns the region the university is in.
+  ///
+  /// `region_name_u` is trusted first (see [`Region::from_name`]). The registry sometimes
+  /// leaves it blank, so when it does not name a region the two-digit region segment of
+  /// `katottgcodeu` is used instead. `None` means neither field resolves to a region.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Region, University};
+  ///
+  /// # let mut university: University = serde_json::from_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/university_min.json"))).unwrap();
+  /// university.region_name_u = "м. Київ".to_string();
+  /// assert_eq!(university.region(), Some(Region::KyivCity));
+  ///
+  /// university.region_name_u = String::new();
+  /// university.katottgcodeu = "UA46060250010015970".to_string();
+  /// assert_eq!(university.region(), Some(Region::LvivOblast));
+  ///
+  /// university.katottgcodeu = String::new();
+  /// assert_eq!(university.region(), None);
+  /// ```
+  pub fn region(&self) -> Option<Region> {
+    resolve_region(&self.region_name_u, &self.katottgcodeu)
+  }
+
+  /// Returns `university_address_u` trimmed, with runs of whitespace inside it collapsed to
+  /// one space. The field itself is left as the registry sent it.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # let mut university: libedbo::University = serde_json::from_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/university_min.json"))).unwrap();
+  /// university.university_address_u = " вул.  Володимирська,\tбуд. 60 ".to_string();
+  /// assert_eq!(university.address(), "вул. Володимирська, буд. 60");
+  /// ```
+  pub fn address(&self) -> Cow<'_, str> {
+    normalize_ws(&self.university_address_u)
+  }
+
+  /// Returns `university_director_fio`, the head's full name, trimmed and with runs of
+  /// whitespace inside it collapsed to one space.
+  pub fn director(&self) -> Cow<'_, str> {
+    normalize_ws(&self.university_director_fio)
+  }
+
+  /// Returns `university_phone` in the canonical `+380XXXXXXXXX` form.
+  ///
+  /// See [`normalize_phone`] for the accepted formats; `None` means the number could not be
+  /// parsed confidently.
+  pub fn phone_normalized(&self) -> Option<String> {
+    normalize_phone(&self.university_phone)
+  }
+
+  /// Returns the addresses listed in `university_email`. See [`split_emails`].
+  pub fn emails(&self) -> Vec<String> {
+    split_emails(&self.university_email)
+  }
+
+  /// Returns the addresses listed in `university_email` that are syntactically valid. See [`is_valid_email`].
+  pub fn valid_emails(&self) -> Vec<String> {
+    self.emails().into_iter().filter(|email| is_valid_email(email)).collect()
+  }
+
+  /// Returns the university's name and contact details, cleaned up. See [`ContactCard`].
+  pub fn contact_card(&self) -> ContactCard {
+    ContactCard::from_fields(
+      &self.university_name,
+      &self.university_phone,
+      &self.university_email,
+      &self.university_site,
+      &self.university_address_u,
+      &self.university_director_fio,
+    )
+  }
+
+  /// Returns `university_site` as a URL, cleaning up the forms the registry commonly holds.
+  ///
+  /// Surrounding whitespace is trimmed and `https://` is prepended when the site has no scheme.
+  /// Blank and unparseable values yield `None`. Requires the `url` feature.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # #[cfg(feature = "url")]
+  /// # fn main() {
+  /// # let mut university: libedbo::University = serde_json::from_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/university_min.json"))).unwrap();
+  /// for site in ["knu.ua", " https://knu.ua/ ", "http://knu.ua", "www.knu.ua"] {
+  ///     university.university_site = site.to_string();
+  ///     assert_eq!(university.website_url().unwrap().host_str().unwrap().trim_start_matches("www."), "knu.ua");
+  /// }
+  /// for site in ["", "   ", "-", "ftp://knu.ua", "http://"] {
+  ///     university.university_site = site.to_string();
+  ///     assert!(university.website_url().is_none());
+  /// }
+  /// # }
+  /// # #[cfg(not(feature = "url"))]
+  /// # fn main() {}
+  /// ```
+  #[cfg(feature = "url")]
+  pub fn website_url(&self) -> Option<url::Url> {
+    super::website::website_url(&self.university_site)
+  }
+
+  /// Returns the total number of licensed places across all speciality licenses.
+  ///
+  /// The sum saturates at `u32::MAX` rather than overflowing on a garbled record, as do
+  /// [`University::full_time_places`] and [`University::part_time_places`].
+  pub fn total_licensed_places(&self) -> u32 {
+    self.speciality_licenses.iter().fold(0, |total, license| total.saturating_add(license.all_count))
+  }
+
+  /// Returns the number of licensed full-time places across all speciality licenses.
+  pub fn full_time_places(&self) -> u32 {
+    self.speciality_licenses.iter().fold(0, |total, license| total.saturating_add(license.full_time_count))
+  }
+
+  /// Returns the number of licensed part-time places across all speciality licenses.
+  pub fn part_time_places(&self) -> u32 {
+    self.speciality_licenses.iter().fold(0, |total, license| total.saturating_add(license.part_time_count))
+  }
+
+  /// Returns the number of distinct speciality codes the university is licensed for.
+  pub fn distinct_specialities(&self) -> usize {
+    self
+      .speciality_licenses
+      .iter()
+      .map(|license| license.speciality_code.as_str())
+      .collect::<HashSet<_>>()
+      .len()
+  }
+
+  /// Returns the first speciality license with the given code, e.g. `"121"`.
+  ///
+  /// Surrounding whitespace on either side is ignored. A university can hold several
+  /// licenses for one code, one per qualification level; use
+  /// [`University::specialities_in_group`] or iterate `speciality_licenses` to see them all.
+  pub fn speciality(&self, code: &str) -> Option<&SpecialityLicense> {
+    let code = code.trim();
+    self.speciality_licenses.iter().find(|license| license.speciality_code.trim() == code)
+  }
+
+  /// Returns the speciality licenses of a qualification group, e.g. `"Бакалавр"`.
+  ///
+  /// Group names are compared ignoring case and surrounding whitespace.
+  pub fn specialities_in_group(&self, group_name: &str) -> Vec<&SpecialityLicense> {
+    let group_name = group_name.trim().to_lowercase();
+    self
+      .speciality_licenses
+      .iter()
+      .filter(|license| license.qualification_group_name.trim().to_lowercase() == group_name)
+      .collect()
+  }
+
+  /// Groups the educator records by `speciality_code`, keeping the registry's order within
+  /// each group.
+  pub fn educators_by_speciality(&self) -> HashMap<String, Vec<&Educator>> {
+    let mut groups: HashMap<String, Vec<&Educator>> = HashMap::new();
+    for educator in &self.educators {
+      groups.entry(educator.speciality_code.clone()).or_default().push(educator);
+    }
+    groups
+  }
+
+  /// Returns the number of educators for `speciality_code`, summed over every educator record
+  /// with that code. See [`Educator::total`].
+  pub fn staff_count(&self, speciality_code: &str) -> u32 {
+    self
+      .educators
+      .iter()
+      .filter(|educator| educator.speciality_code == speciality_code)
+      .map(Educator::total)
+      .fold(0, u32::saturating_add)
+  }
+
+  /// Returns the faculty names from `facultets`, trimmed, without blanks and without repeats.
+  ///
+  /// The first occurrence of each name is kept, in the registry's order. The raw list is left
+  /// untouched in `facultets`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// # let mut university: libedbo::University = serde_json::from_str(include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/university_min.json"))).unwrap();
+  /// university.facultets = vec![
+  ///     " Факультет права ".to_string(),
+  ///     "Історичний факультет".to_string(),
+  ///     "".to_string(),
+  ///     "Факультет права".to_string(),
+  /// ];
+  /// assert_eq!(university.faculties(), ["Факультет права", "Історичний факультет"]);
+  /// assert_eq!(university.faculty_count(), 2);
+  /// ```
+  pub fn faculties(&self) -> Vec<&str> {
+    let mut seen = HashSet::new();
+    self
+      .facultets
+      .iter()
+      .map(|faculty| faculty.trim())
+      .filter(|faculty| !faculty.is_empty() && seen.insert(*faculty))
+      .collect()
+  }
+
+  /// Returns the number of distinct faculties. See [`University::faculties`].
+  pub fn faculty_count(&self) -> usize {
+    self.faculties().len()
+  }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The summary of a university listed by `/api/universities`.
+///
+/// # Serialization
+///
+/// Round-trips like [`University`]: the output deserializes to an equal record, but
+/// `university_parent_id`, `is_from_crimea`, `registration_year` and, with `chrono`,
+/// `close_date` are written in normalized form rather than as the registry sent them.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::UniversityBrief;
+/// use serde_json::json;
+///
+/// # let fixture = json!({
+/// #     "university_name": "Національний університет «Львівська політехніка»", "university_id": "79",
+/// #     "university_parent_id": "", "university_short_name": "НУ «Львівська політехніка»",
+/// #     "university_name_en": "Lviv Polytechnic National University", "is_from_crimea": "0",
+/// #     "registration_year": "1844", "university_type_name": "Університет",
+/// #     "university_financing_type_name": "Державна", "university_governance_type_name": "Міністерство освіти і науки України",
+/// #     "post_index_u": "79013", "katottgcodeu": "UA46060250010015970", "katottg_name_u": "м. Львів",
+/// #     "region_name_u": "Львівська область", "university_address_u": "вул. Степана Бандери, 12",
+/// #     "university_phone": "(032) 258-22-82", "university_email": "coffice@lpnu.ua", "university_site": "lpnu.ua",
+/// #     "university_director_post": "Ректор", "university_director_fio": "Бобало Юрій Ярославович",
+/// #     "close_date": "", "primitki": ""
+/// # });
+/// let variants = [
+///     ("university_parent_id", vec![json!(""), json!(null), json!("78")]),
+///     ("is_from_crimea", vec![json!("1"), json!(0), json!(false)]),
+///     ("registration_year", vec![json!(""), json!(1844)]),
+///     ("close_date", vec![json!(""), json!(null), json!("2021-06-30 00:00:00")]),
+/// ];
+/// for (key, values) in variants {
+///     for value in values {
+///         let mut raw = fixture.clone();
+///         raw[key] = value;
+///         let brief: UniversityBrief = serde_json::from_value(raw).unwrap();
+///         let json = serde_json::to_string(&brief).unwrap();
+///         assert_eq!(serde_json::from_str::<UniversityBrief>(&json).unwrap(), brief);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UniversityBrief {
   pub university_name: String,
-  pub university_id: String,
-  pub university_parent_id: Option<String>,
+  pub university_id: UniversityId,
+  #[serde(default, deserialize_with = "de::optional_id")]
+  pub university_parent_id: Option<UniversityId>,
   pub university_short_name: String,
   pub university_name_en: String,
-  pub is_from_crimea: String,
-  pub registration_year: String,
+  #[serde(default, deserialize_with = "de::flag")]
+  pub is_from_crimea: bool,
+  #[serde(default, deserialize_with = "de::registration_year")]
+  pub registration_year: Option<u16>,
   pub university_type_name: String,
   pub university_financing_type_name: String,
   pub university_governance_type_name: String,
@@ -114,6 +815,271 @@ pub struct UniversityBrief {
   pub university_site: String,
   pub university_director_post: String,
   pub university_director_fio: String,
+  /// The date the institution was closed, if it has been.
+  ///
+  /// With the `chrono` feature enabled this is parsed from the registry's `YYYY-MM-DD` format.
+  #[cfg(feature = "chrono")]
+  #[serde(default, deserialize_with = "de::close_date")]
+  pub close_date: Option<chrono::NaiveDate>,
+  /// The date the institution was closed, if it has been, as sent by the registry.
+  #[cfg(not(feature = "chrono"))]
   pub close_date: Option<String>,
-  pub primitki: String
-}
\ No newline at end of file
+  pub primitki: String,
+  /// Keys sent by the registry that the model does not have a field for.
+  ///
+  /// Only present with the `extra-fields` feature. The keys are written back out on
+  /// serialization, so a record round-trips without losing data.
+  #[cfg(feature = "extra-fields")]
+  #[serde(flatten)]
+  pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Hashes only `university_id`.
+///
+/// Equality still compares every field, which keeps `Hash` consistent with `Eq`: a set
+/// collapses the same university listed under several regions, but keeps two versions of a
+/// record that changed between fetches. To keep one record per ID regardless of content, key
+/// a map by `university_id` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use libedbo::UniversityBrief;
+///
+/// let json = r#"{"university_name": "КПІ", "university_id": 174, "university_short_name": "",
+///     "university_name_en": "", "university_type_name": "", "university_financing_type_name": "",
+///     "university_governance_type_name": "", "post_index_u": "", "katottgcodeu": "", "katottg_name_u": "",
+///     "region_name_u": "", "university_address_u": "", "university_phone": "", "university_email": "",
+///     "university_site": "", "university_director_post": "", "university_director_fio": "",
+///     "close_date": null, "primitki": ""}"#;
+/// let list = UniversityBrief::list_from_json_str(&format!("[{json}, {json}]"))?;
+/// assert_eq!(list.into_iter().collect::<HashSet<_>>().len(), 1);
+/// # Ok::<(), libedbo::error::Error>(())
+/// ```
+impl Hash for UniversityBrief {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.university_id.hash(state);
+  }
+}
+
+/// Keeps the fields a university listing carries and drops the branches, licenses, faculties
+/// and educators.
+///
+/// The full record has no `primitki`, so the brief's is left empty. With the `extra-fields`
+/// feature, the record's unknown keys are carried over.
+impl From<University> for UniversityBrief {
+  fn from(university: University) -> Self {
+    UniversityBrief {
+      university_name: university.university_name,
+      university_id: university.university_id,
+      university_parent_id: university.university_parent_id,
+      university_short_name: university.university_short_name,
+      university_name_en: university.university_name_en,
+      is_from_crimea: university.is_from_crimea,
+      registration_year: university.registration_year,
+      university_type_name: university.university_type_name,
+      university_financing_type_name: university.university_financing_type_name,
+      university_governance_type_name: university.university_governance_type_name,
+      post_index_u: university.post_index_u,
+      katottgcodeu: university.katottgcodeu,
+      katottg_name_u: university.katottg_name_u,
+      region_name_u: university.region_name_u,
+      university_address_u: university.university_address_u,
+      university_phone: university.university_phone,
+      university_email: university.university_email,
+      university_site: university.university_site,
+      university_director_post: university.university_director_post,
+      university_director_fio: university.university_director_fio,
+      close_date: university.close_date,
+      primitki: String::new(),
+      #[cfg(feature = "extra-fields")]
+      extra: university.extra,
+    }
+  }
+}
+
+/// Summarizes the university on one line: its Ukrainian name, short name, region and ID.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::UniversityBrief;
+///
+/// let json = r#"[{
+///     "university_name": "Київський національний університет імені Тараса Шевченка", "university_id": "41",
+///     "university_short_name": "КНУ імені Тараса Шевченка", "university_name_en": "", "university_type_name": "",
+///     "university_financing_type_name": "", "university_governance_type_name": "", "post_index_u": "",
+///     "katottgcodeu": "", "katottg_name_u": "", "region_name_u": "м. Київ", "university_address_u": "",
+///     "university_phone": "", "university_email": "", "university_site": "", "university_director_post": "",
+///     "university_director_fio": "", "close_date": null, "primitki": ""
+/// }]"#;
+/// let university = &UniversityBrief::list_from_json_str(json)?[0];
+/// assert_eq!(
+///     university.to_string(),
+///     "Київський національний університет імені Тараса Шевченка (КНУ імені Тараса Шевченка) — м. Київ, 41",
+/// );
+/// # Ok::<(), libedbo::error::Error>(())
+/// ```
+impl fmt::Display for UniversityBrief {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write_summary(f, &self.university_name, &self.university_short_name, &self.region_name_u, self.university_id)
+  }
+}
+
+impl UniversityBrief {
+  /// Deserializes a list of universities from the JSON returned by `/api/universities`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::UniversityBrief;
+  ///
+  /// assert!(UniversityBrief::list_from_json_str("[]").unwrap().is_empty());
+  /// assert!(UniversityBrief::list_from_json_str("<html>").is_err());
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if the JSON is malformed or does not match the model.
+  pub fn list_from_json_str(json: &str) -> Result<Vec<UniversityBrief>, Error> {
+    Ok(serde_json::from_str(json)?)
+  }
+
+  /// Deserializes a list of universities from a reader yielding the JSON returned by
+  /// `/api/universities`.
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::ParsingError` if reading fails or the JSON does not match the model.
+  pub fn list_from_json_reader<R: Read>(reader: R) -> Result<Vec<UniversityBrief>, Error> {
+    Ok(serde_json::from_reader(reader)?)
+  }
+
+  /// Returns the address of the university's page on the public registry site. See
+  /// [`University::profile_url`].
+  pub fn profile_url(&self) -> String {
+    profile_url(crate::UNIVERSITY_PAGE, self.university_id)
+  }
+
+  /// Returns the region the university is in, falling back to `katottgcodeu` when
+  /// `region_name_u` is blank. See [`University::region`].
+  pub fn region(&self) -> Option<Region> {
+    resolve_region(&self.region_name_u, &self.katottgcodeu)
+  }
+
+  /// Returns `university_address_u` with its whitespace normalized. See
+  /// [`University::address`].
+  pub fn address(&self) -> Cow<'_, str> {
+    normalize_ws(&self.university_address_u)
+  }
+
+  /// Returns `university_director_fio` trimmed, with every run of whitespace inside the name
+  /// collapsed to a single space.
+  pub fn director(&self) -> Cow<'_, str> {
+    normalize_ws(&self.university_director_fio)
+  }
+
+  /// Returns `university_phone` in the canonical `+380XXXXXXXXX` form. See [`normalize_phone`].
+  pub fn phone_normalized(&self) -> Option<String> {
+    normalize_phone(&self.university_phone)
+  }
+
+  /// Returns the addresses listed in `university_email`. See [`split_emails`].
+  pub fn emails(&self) -> Vec<String> {
+    split_emails(&self.university_email)
+  }
+
+  /// Returns the addresses listed in `university_email` that are syntactically valid. See [`is_valid_email`].
+  pub fn valid_emails(&self) -> Vec<String> {
+    self.emails().into_iter().filter(|email| is_valid_email(email)).collect()
+  }
+
+  /// Returns the university's name and contact details, cleaned up. See [`ContactCard`].
+  pub fn contact_card(&self) -> ContactCard {
+    ContactCard::from_fields(
+      &self.university_name,
+      &self.university_phone,
+      &self.university_email,
+      &self.university_site,
+      &self.university_address_u,
+      &self.university_director_fio,
+    )
+  }
+
+  /// Returns the university's name in `lang`.
+  ///
+  /// The English name is `university_name_en`, which the registry leaves blank for many
+  /// institutions; the Ukrainian name is returned in that case.
+  pub fn name(&self, lang: Lang) -> &str {
+    match lang {
+      Lang::En if !self.university_name_en.trim().is_empty() => &self.university_name_en,
+      _ => &self.university_name,
+    }
+  }
+
+  /// Classifies `university_type_name`. See [`UniversityType::from_name`].
+  pub fn university_type(&self) -> UniversityType {
+    UniversityType::from_name(&self.university_type_name)
+  }
+
+  /// Classifies `university_financing_type_name`. See [`FinancingType::from_name`].
+  pub fn financing_type(&self) -> FinancingType {
+    FinancingType::from_name(&self.university_financing_type_name)
+  }
+
+  /// Classifies `university_governance_type_name`. See [`GovernanceType::from_name`].
+  pub fn governance_type(&self) -> GovernanceType {
+    GovernanceType::from_name(&self.university_governance_type_name)
+  }
+
+  /// Returns true if the registry lists a close date for the university.
+  ///
+  /// A blank `close_date` counts as open.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::UniversityBrief;
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// # let record = |id: i32, close_date: &str| format!(r#"{{"university_name": "", "university_id": "{id}",
+  /// #     "university_short_name": "", "university_name_en": "", "university_type_name": "",
+  /// #     "university_financing_type_name": "", "university_governance_type_name": "", "post_index_u": "",
+  /// #     "katottgcodeu": "", "katottg_name_u": "", "region_name_u": "", "university_address_u": "",
+  /// #     "university_phone": "", "university_email": "", "university_site": "", "university_director_post": "",
+  /// #     "university_director_fio": "", "close_date": {close_date}, "primitki": ""}}"#);
+  /// let json = format!("[{}, {}, {}]", record(1, r#""""#), record(2, "null"), record(3, r#""2020-08-31""#));
+  /// let open: Vec<_> = UniversityBrief::list_from_json_str(&json)?
+  ///     .into_iter()
+  ///     .filter(|university| !university.is_closed())
+  ///     .map(|university| university.university_id.0)
+  ///     .collect();
+  /// assert_eq!(open, [1, 2]);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn is_closed(&self) -> bool {
+    is_closed(&self.close_date)
+  }
+
+  /// Returns true if the university was closed before `date`.
+  ///
+  /// Requires the `chrono` feature.
+  #[cfg(feature = "chrono")]
+  pub fn closed_before(&self, date: chrono::NaiveDate) -> bool {
+    self.close_date.is_some_and(|closed| closed < date)
+  }
+}
+
+/// Returns true if `close_date` holds a date.
+#[cfg(feature = "chrono")]
+fn is_closed(close_date: &Option<chrono::NaiveDate>) -> bool {
+  close_date.is_some()
+}
+
+/// Returns true if `close_date` holds a non-blank date.
+#[cfg(not(feature = "chrono"))]
+fn is_closed(close_date: &Option<String>) -> bool {
+  close_date.as_deref().is_some_and(|date| !date.trim().is_empty())
+}
@@ -0,0 +1,100 @@
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use super::lang::Lang;
+use super::fold_label;
+
+/// The kind of higher or pre-higher education institution, from `university_type_name`.
+///
+/// Names the crate does not recognize, including combined forms the registry uses for some
+/// records, are kept verbatim in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum UniversityType {
+  University,          // Університет
+  Academy,             // Академія
+  Institute,           // Інститут
+  College,             // Коледж
+  ProfessionalCollege, // Фаховий коледж
+  TechnicalSchool,     // Технікум
+  VocationalSchool,    // Училище
+  ResearchInstitution, // Наукова установа
+  Other(String),
+}
+
+impl fmt::Display for UniversityType {
+  fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    write!(f, "{}", self.name_en())
+  }
+}
+
+impl UniversityType {
+  /// Every known institution type, in declaration order.
+  pub const ALL: [UniversityType; 8] = [
+    UniversityType::University,
+    UniversityType::Academy,
+    UniversityType::Institute,
+    UniversityType::College,
+    UniversityType::ProfessionalCollege,
+    UniversityType::TechnicalSchool,
+    UniversityType::VocationalSchool,
+    UniversityType::ResearchInstitution,
+  ];
+
+  /// Returns the Ukrainian name of the type, or the name the registry sent for `Other`.
+  pub fn name_uk(&self) -> &str {
+    match self {
+      UniversityType::University          => "Університет",
+      UniversityType::Academy             => "Академія",
+      UniversityType::Institute           => "Інститут",
+      UniversityType::College             => "Коледж",
+      UniversityType::ProfessionalCollege => "Фаховий коледж",
+      UniversityType::TechnicalSchool     => "Технікум",
+      UniversityType::VocationalSchool    => "Училище",
+      UniversityType::ResearchInstitution => "Наукова установа",
+      UniversityType::Other(name) => name,
+    }
+  }
+
+  /// Returns the English name of the type. `Other` falls back to its Ukrainian name.
+  pub fn name_en(&self) -> &str {
+    match self {
+      UniversityType::University          => "University",
+      UniversityType::Academy             => "Academy",
+      UniversityType::Institute           => "Institute",
+      UniversityType::College             => "College",
+      UniversityType::ProfessionalCollege => "Professional college",
+      UniversityType::TechnicalSchool     => "Technical school",
+      UniversityType::VocationalSchool    => "Vocational school",
+      UniversityType::ResearchInstitution => "Research institution",
+      UniversityType::Other(name) => name,
+    }
+  }
+
+  /// Returns the name of the type in `lang`.
+  pub fn name(&self, lang: Lang) -> &str {
+    match lang {
+      Lang::Uk => self.name_uk(),
+      Lang::En => self.name_en(),
+    }
+  }
+
+  /// Recognizes an institution type from `university_type_name`, ignoring case, spacing,
+  /// apostrophes and stress marks, as name searches do. Anything else becomes `Other`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::UniversityType;
+  ///
+  /// assert_eq!(UniversityType::from_name("УНІВЕРСИТЕТ"), UniversityType::University);
+  /// assert_eq!(UniversityType::from_name("Фаховий  коледж "), UniversityType::ProfessionalCollege);
+  /// assert_eq!(UniversityType::from_name("Фаховий ко\u{301}ледж"), UniversityType::ProfessionalCollege);
+  /// assert!(matches!(UniversityType::from_name("Ліцей"), UniversityType::Other(name) if name == "Ліцей"));
+  /// ```
+  pub fn from_name(name: &str) -> UniversityType {
+    let folded = fold_label(name);
+    UniversityType::ALL
+      .into_iter()
+      .find(|kind| fold_label(kind.name_uk()) == folded)
+      .unwrap_or_else(|| UniversityType::Other(name.trim().to_string()))
+  }
+}
@@ -0,0 +1,23 @@
+//! Cleanup of the free-form website fields the registry returns.
+
+use url::Url;
+
+/// Parses a website as entered in the registry into an absolute `http(s)` URL.
+///
+/// Surrounding whitespace is trimmed and `https://` is assumed when no scheme is given. Blank
+/// values, other schemes and anything without a host yield `None`.
+pub(crate) fn website_url(raw: &str) -> Option<Url> {
+  let raw = raw.trim();
+  if raw.is_empty() {
+    return None;
+  }
+  let url = if raw.contains("://") {
+    Url::parse(raw).ok()?
+  } else {
+    Url::parse(&format!("https://{raw}")).ok()?
+  };
+  match url.scheme() {
+    "http" | "https" if url.host_str().is_some_and(|host| host.contains('.')) => Some(url),
+    _ => None,
+  }
+}
@@ -0,0 +1,59 @@
+use crate::error::Error;
+
+/// One page of a listing, together with the size of the whole listing.
+///
+/// The registry's listing endpoints return every matching record as a bare JSON array, with
+/// no count or paging envelope. Pages are therefore cut from the full response in the crate:
+/// fetching a page downloads the whole listing, and `total` is exact.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+  /// The records on this page.
+  pub items: Vec<T>,
+  /// The zero-based index of this page.
+  pub page: usize,
+  /// The maximum number of records per page.
+  pub page_size: usize,
+  /// The number of records in the whole listing.
+  pub total: usize,
+}
+
+impl<T> Page<T> {
+  /// Cuts page `page` of `page_size` records out of `records`.
+  ///
+  /// A page past the end of the listing is empty.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::Page;
+  ///
+  /// let page = Page::from_records((1..=25).collect(), 2, 10)?;
+  /// assert_eq!(page.items, [21, 22, 23, 24, 25]);
+  /// assert_eq!(page.total, 25);
+  /// assert_eq!(page.page_count(), 3);
+  /// assert!(!page.has_next());
+  /// # Ok::<(), libedbo::error::Error>(())
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidValue` if `page_size` is 0.
+  pub fn from_records(records: Vec<T>, page: usize, page_size: usize) -> Result<Page<T>, Error> {
+    if page_size == 0 {
+      return Err(Error::InvalidValue { kind: "page size", value: page_size.to_string() });
+    }
+    let total = records.len();
+    let items = records.into_iter().skip(page.saturating_mul(page_size)).take(page_size).collect();
+    Ok(Page { items, page, page_size, total })
+  }
+
+  /// Returns the number of pages the listing spans.
+  pub fn page_count(&self) -> usize {
+    self.total.div_ceil(self.page_size)
+  }
+
+  /// Returns true if there is a page after this one.
+  pub fn has_next(&self) -> bool {
+    self.page.saturating_add(1) < self.page_count()
+  }
+}
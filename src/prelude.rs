@@ -0,0 +1,28 @@
+//! The types and functions most programs need, for glob import.
+//!
+//! ```rust,no_run
+//! use libedbo::prelude::*;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Error> {
+//! let client = EdboClient::new();
+//! let params = SearchParams::new()
+//!     .with_region(Region::LvivOblast)
+//!     .with_university_category(UniversityCategory::HigherEducationInstitutions);
+//! for university in client.search_universities(params).await? {
+//!     println!("{university}");
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Everything here is also exported from the crate root.
+
+pub use crate::error::Error;
+pub use crate::{
+  EdboClient, EdboClientBuilder, FinancingType, Institution, InstitutionCategory, Operation, Region, SearchParams,
+  University, UniversityBrief, UniversityCategory, UniversityId, InstitutionId,
+};
+pub use crate::{search_institutions_async, search_school_async, search_universities_async, search_university_async};
+#[cfg(feature = "blocking")]
+pub use crate::{search_institutions, search_school, search_universities, search_university};
@@ -0,0 +1,66 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Duration;
+use std::time::SystemTime;
+use serde::{Deserialize, Serialize};
+
+use crate::search::SearchParams;
+
+/// The data returned by a search, together with the parameters that produced it and the time
+/// it was fetched.
+///
+/// Returned by the `*_with_meta` search methods, such as
+/// [`EdboClient::search_universities_with_meta`](crate::EdboClient::search_universities_with_meta).
+/// The bundle serializes as a whole, so it can be written to an on-disk cache and checked for
+/// staleness when it is read back.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use libedbo::{SearchParams, SearchResult};
+///
+/// let result = SearchResult::new(SearchParams::new().with_id(41), vec!["КНУ"]);
+/// assert!(!result.is_older_than(Duration::from_secs(60)));
+///
+/// let json = serde_json::to_string(&result).unwrap();
+/// let cached: SearchResult<Vec<String>> = serde_json::from_str(&json).unwrap();
+/// assert_eq!(cached.params.id, Some(41));
+/// assert_eq!(cached.fetched_at, result.fetched_at);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult<T> {
+  /// The parameters the search was run with.
+  pub params: SearchParams,
+  /// When the search returned. With a disk or memory cache enabled the data itself may have
+  /// been fetched from the registry earlier.
+  pub fetched_at: SystemTime,
+  /// What the search returned.
+  pub data: T,
+}
+
+impl<T> SearchResult<T> {
+  /// Bundles `data` with `params`, stamped with the current time.
+  ///
+  /// Reading the clock panics on `wasm32-unknown-unknown`, so this is not available there.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn new(params: SearchParams, data: T) -> SearchResult<T> {
+    SearchResult { params, fetched_at: SystemTime::now(), data }
+  }
+
+  /// Returns how long ago the result was fetched, or zero if `fetched_at` is in the future.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn age(&self) -> Duration {
+    self.fetched_at.elapsed().unwrap_or_default()
+  }
+
+  /// Returns true if the result was fetched more than `max_age` ago.
+  #[cfg(not(target_arch = "wasm32"))]
+  pub fn is_older_than(&self, max_age: Duration) -> bool {
+    self.age() > max_age
+  }
+
+  /// Replaces the data, keeping the parameters and timestamp.
+  pub fn map<U>(self, f: impl FnOnce(T) -> U) -> SearchResult<U> {
+    SearchResult { params: self.params, fetched_at: self.fetched_at, data: f(self.data) }
+  }
+}
@@ -0,0 +1,29 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Spaces requests evenly so that no more than a fixed number start in any one second.
+///
+/// Each request reserves the next free slot and waits until it comes round, so requests over
+/// the limit queue in the order they arrived. A request that finds the limiter idle starts at
+/// once.
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+  interval: Duration,
+  next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+  /// Creates a limiter allowing `requests_per_second` requests per second, which must be at least 1.
+  pub(crate) fn new(requests_per_second: u32) -> Self {
+    RateLimiter { interval: Duration::from_secs(1) / requests_per_second, next_slot: Mutex::new(None) }
+  }
+
+  /// Reserves the next slot and returns how long to wait before sending the request.
+  pub(crate) fn reserve(&self) -> Duration {
+    let now = Instant::now();
+    let mut next_slot = self.next_slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    let slot = next_slot.map_or(now, |slot| slot.max(now));
+    *next_slot = Some(slot + self.interval);
+    slot - now
+  }
+}
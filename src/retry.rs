@@ -0,0 +1,88 @@
+use std::hash::{BuildHasher, RandomState};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::SystemTime;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+
+use crate::error::Error;
+
+/// The default delay before the first retry.
+pub(crate) const DEFAULT_RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+
+/// Decides whether and when a failed request is retried.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RetryPolicy {
+  pub(crate) retries: u32,
+  pub(crate) base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+  fn default() -> Self {
+    RetryPolicy { retries: 0, base_delay: DEFAULT_RETRY_BASE_DELAY }
+  }
+}
+
+impl RetryPolicy {
+  /// Returns how long to wait before retrying a request whose `attempt`-th retry (starting at 0)
+  /// would follow `error`, or `None` if the error should be returned to the caller.
+  ///
+  /// A rate-limited request waits for the server's `Retry-After` delay when one was sent.
+  /// Otherwise the delay doubles with every attempt, and a random jitter of up to half of it is
+  /// subtracted so that concurrent clients do not retry in lockstep.
+  pub(crate) fn delay(&self, attempt: u32, error: &Error) -> Option<Duration> {
+    let error = error.inner();
+    if attempt >= self.retries || !is_transient(error) {
+      return None;
+    }
+    if let Error::RateLimited { retry_after: Some(retry_after) } = error {
+      return Some(*retry_after);
+    }
+    let delay = self.base_delay.saturating_mul(2u32.saturating_pow(attempt));
+    let jitter = RandomState::new().hash_one(attempt) % 1_000;
+    Some(delay - delay / 2 / 1_000 * jitter as u32)
+  }
+}
+
+/// Returns true for failures that may succeed when the same request is repeated.
+fn is_transient(error: &Error) -> bool {
+  match error {
+    Error::NetworkError(err) => !err.is_decode() && !err.is_builder(),
+    Error::Timeout(_) | Error::Connect(_) | Error::Dns(_) => true,
+    Error::ApiError { status, .. } => *status >= 500,
+    Error::RateLimited { .. } => true,
+    _ => false,
+  }
+}
+
+/// Waits for `duration` before the next attempt.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn sleep(duration: Duration) {
+  tokio::time::sleep(duration).await
+}
+
+/// Waits for `duration` before the next attempt, using the browser's timers.
+#[cfg(target_arch = "wasm32")]
+pub(crate) async fn sleep(duration: Duration) {
+  gloo_timers::future::sleep(duration).await
+}
+
+/// Reads the `Retry-After` header, given either as a number of seconds or as an HTTP date.
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+  let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+  if let Ok(seconds) = value.parse::<u64>() {
+    return Some(Duration::from_secs(seconds));
+  }
+  retry_after_date(value)
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn retry_after_date(value: &str) -> Option<Duration> {
+  let date = httpdate::parse_http_date(value).ok()?;
+  Some(date.duration_since(SystemTime::now()).unwrap_or(Duration::ZERO))
+}
+
+/// The system clock is unavailable on wasm32, so an HTTP date falls back to the backoff schedule.
+#[cfg(target_arch = "wasm32")]
+fn retry_after_date(_value: &str) -> Option<Duration> {
+  None
+}
@@ -1,12 +1,64 @@
-use serde::Serialize;
-use crate::model::{Region, UniversityCategory, InstitutionCategory};
+use serde::{Deserialize, Serialize};
+use crate::{assert_some, INSTITUTIONS_ENDPOINT, SCHOOL_ENDPOINT, UNIVERSITIES_ENDPOINT, UNIVERSITY_ENDPOINT};
+use crate::error::Error;
+use crate::model::{fold_label, FinancingType, Region, UniversityCategory, InstitutionCategory, Institution, UniversityBrief};
 
-#[derive(Debug, Serialize, Default)]
+/// A registry lookup that a set of [`SearchParams`] can be used for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+  /// Listing universities; needs a region and a university category.
+  Universities,
+  /// Fetching a single university; needs a positive ID.
+  University,
+  /// Listing secondary education institutions; needs a region and an institution category.
+  Institutions,
+  /// Fetching a single school; needs a positive ID.
+  School,
+}
+
+impl Operation {
+  /// Returns the API path the operation is served from, e.g. `/api/universities`.
+  pub fn endpoint(&self) -> &'static str {
+    match self {
+      Operation::Universities => UNIVERSITIES_ENDPOINT,
+      Operation::University => UNIVERSITY_ENDPOINT,
+      Operation::Institutions => INSTITUTIONS_ENDPOINT,
+      Operation::School => SCHOOL_ENDPOINT,
+    }
+  }
+
+  /// Returns the operation whose endpoint `url` requests, ignoring the query string.
+  pub(crate) fn from_url(url: &str) -> Option<Operation> {
+    let path = url.split(['?', '#']).next().unwrap_or_default().trim_end_matches('/');
+    [Operation::Universities, Operation::University, Operation::Institutions, Operation::School]
+      .into_iter()
+      .find(|operation| path.ends_with(operation.endpoint()))
+  }
+}
+
+/// Parameters for a registry search, built with the `with_*` methods.
+///
+/// The registry has no language parameter and always answers in Ukrainian. English names are
+/// available through [`Lang`](crate::Lang) and the `name_en` methods of the region and category
+/// enums, and for universities through `university_name_en` where the registry has one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SearchParams {
   pub id: Option<i32>,
   pub region: Option<Region>,
   pub university_category: Option<UniversityCategory>,
-  pub institution_category: Option<InstitutionCategory>
+  pub institution_category: Option<InstitutionCategory>,
+  pub name: Option<String>,
+  pub financing_type: Option<FinancingType>,
+  /// Whether university listings keep records with a close date. See
+  /// [`SearchParams::include_closed`].
+  pub include_closed: bool,
+}
+
+impl Default for SearchParams {
+  fn default() -> Self {
+    SearchParams::new()
+  }
 }
 
 impl SearchParams {
@@ -15,12 +67,51 @@ impl SearchParams {
       id: None,
       region: None,
       university_category: None,
-      institution_category: None
+      institution_category: None,
+      name: None,
+      financing_type: None,
+      include_closed: true,
     }
   }
 
-  pub fn with_id(mut self, id: i32) -> Self {
-    self.id = Some(id);
+  /// Creates parameters for listing the higher education institutions of `region`, the most
+  /// common university search.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Region, SearchParams, UniversityCategory};
+  ///
+  /// let params = SearchParams::universities(Region::LvivOblast);
+  /// assert_eq!(params.region, Some(Region::LvivOblast));
+  /// assert_eq!(params.university_category, Some(UniversityCategory::HigherEducationInstitutions));
+  /// ```
+  pub fn universities(region: Region) -> Self {
+    SearchParams::new().with_region(region).with_university_category(UniversityCategory::HigherEducationInstitutions)
+  }
+
+  /// Creates parameters for listing the general secondary education institutions of `region`.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{InstitutionCategory, Region, SearchParams};
+  ///
+  /// let params = SearchParams::schools(Region::KyivCity);
+  /// assert_eq!(params.institution_category, Some(InstitutionCategory::GeneralSecondaryEducationInstitutions));
+  /// ```
+  pub fn schools(region: Region) -> Self {
+    SearchParams::new().with_region(region).with_institution_category(InstitutionCategory::GeneralSecondaryEducationInstitutions)
+  }
+
+  /// Creates parameters for fetching a single university or school, the same as
+  /// `SearchParams::new().with_id(id)`.
+  pub fn by_id(id: impl Into<i32>) -> Self {
+    SearchParams::new().with_id(id)
+  }
+
+  pub fn with_id(mut self, id: impl Into<i32>) -> Self {
+    self.id = Some(id.into());
     self
   }
 
@@ -38,4 +129,296 @@ impl SearchParams {
     self.institution_category = Some(institution_category);
     self
   }
-}
\ No newline at end of file
+
+  /// Restricts university and institution searches to records whose full or short name
+  /// contains `name`.
+  ///
+  /// The registry has no name filter, so matching happens in the crate after the region and
+  /// category have been fetched. It ignores letter case, apostrophe variants (`'`, `’`, `ʼ`)
+  /// and combining stress marks, so "політехніка" matches "Політехніка".
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{InstitutionCategory, Region, SearchParams};
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::LvivOblast)
+  ///     .with_institution_category(InstitutionCategory::GeneralSecondaryEducationInstitutions)
+  ///     .with_name("ліцей");
+  ///
+  /// let lyceums = libedbo::search_institutions(params)?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn with_name(mut self, name: impl Into<String>) -> Self {
+    self.name = Some(name.into());
+    self
+  }
+
+  /// Restricts university searches to records with the given financing type.
+  ///
+  /// Like [`SearchParams::with_name`], this is applied in the crate after the region and
+  /// category have been fetched, because the registry has no financing filter.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{FinancingType, Region, SearchParams, UniversityCategory};
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::KyivCity)
+  ///     .with_university_category(UniversityCategory::HigherEducationInstitutions)
+  ///     .with_financing_type(FinancingType::Private);
+  ///
+  /// let private = libedbo::search_universities(params)?;
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn with_financing_type(mut self, financing_type: FinancingType) -> Self {
+    self.financing_type = Some(financing_type);
+    self
+  }
+
+  /// Sets whether university searches return universities the registry lists a close date
+  /// for. The default is `true`, which returns every record.
+  ///
+  /// With `false`, records for which [`UniversityBrief::is_closed`] holds are dropped from the
+  /// listing after it arrives, like the name and financing type filters. Institution
+  /// listings carry no close date and are not affected.
+  ///
+  /// # Examples
+  ///
+  /// ```rust,no_run
+  /// use libedbo::{Region, SearchParams, UniversityCategory};
+  ///
+  /// # fn main() -> Result<(), libedbo::error::Error> {
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::KharkivOblast)
+  ///     .with_university_category(UniversityCategory::HigherEducationInstitutions)
+  ///     .include_closed(false);
+  ///
+  /// let operating = libedbo::search_universities(params)?;
+  /// assert!(operating.iter().all(|university| !university.is_closed()));
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn include_closed(mut self, include_closed: bool) -> Self {
+    self.include_closed = include_closed;
+    self
+  }
+
+  /// Checks that the parameters needed by `operation` are present and valid.
+  ///
+  /// This performs the same checks the search functions run before making a request, so it
+  /// can be used to reject a query without touching the network.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Operation, Region, SearchParams, UniversityCategory};
+  ///
+  /// let params = SearchParams::new().with_region(Region::KyivCity);
+  /// assert!(params.validate_for(Operation::Universities).is_err());
+  ///
+  /// let params = params.with_university_category(UniversityCategory::HigherEducationInstitutions);
+  /// assert!(params.validate_for(Operation::Universities).is_ok());
+  ///
+  /// assert!(SearchParams::new().with_id(0).validate_for(Operation::University).is_err());
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::OtherError` naming the first missing field, or describing an ID below 1.
+  pub fn validate_for(&self, operation: Operation) -> Result<(), Error> {
+    self.query(operation).map(|_| ())
+  }
+
+  /// Rejects combinations of parameters that cannot produce a meaningful result, whatever
+  /// operation they are used for.
+  ///
+  /// [`SearchParams::validate_for`] only checks that the fields an operation needs are
+  /// present. The search functions quietly ignore fields an operation does not use, so a
+  /// query mixing them can come back empty or differ from what was meant. This method
+  /// catches those cases:
+  ///
+  /// - a university category and an institution category together: a search lists either
+  ///   universities or institutions, never both;
+  /// - an ID together with a region, category, name or financing type: lookups by ID use
+  ///   the ID alone;
+  /// - an ID below 1: the registry numbers its records from 1;
+  /// - a financing type together with an institution category: only university listings
+  ///   carry a financing type that can be filtered on;
+  /// - a name filter that is empty or only whitespace: it would match every record.
+  ///
+  /// Whether a region actually has records of a category is not checked. The registry
+  /// publishes no such table, and regions such as the Republic of Crimea, which answer
+  /// with empty lists, are still valid queries.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{FinancingType, InstitutionCategory, Region, SearchParams, UniversityCategory};
+  ///
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::KyivCity)
+  ///     .with_university_category(UniversityCategory::HigherEducationInstitutions)
+  ///     .with_financing_type(FinancingType::Private);
+  /// assert!(params.validate_combination().is_ok());
+  ///
+  /// let params = SearchParams::new()
+  ///     .with_region(Region::KyivCity)
+  ///     .with_institution_category(InstitutionCategory::PreschoolEducationInstitutions)
+  ///     .with_financing_type(FinancingType::Private);
+  /// assert!(params.validate_combination().is_err());
+  ///
+  /// assert!(SearchParams::new().with_id(41).with_region(Region::LvivOblast).validate_combination().is_err());
+  /// assert!(SearchParams::new().with_name("  ").validate_combination().is_err());
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns `Error::InvalidValue` describing the first rule the parameters break.
+  pub fn validate_combination(&self) -> Result<(), Error> {
+    let invalid = |value: &str| Err(Error::InvalidValue { kind: "search parameter combination", value: value.to_string() });
+    if self.university_category.is_some() && self.institution_category.is_some() {
+      return invalid("university_category and institution_category cannot both be set");
+    }
+    if let Some(id) = self.id {
+      if id < 1 {
+        return invalid(&format!("id must be positive, got {id}"));
+      }
+      let filters = [
+        ("region", self.region.is_some()),
+        ("university_category", self.university_category.is_some()),
+        ("institution_category", self.institution_category.is_some()),
+        ("name", self.name.is_some()),
+        ("financing_type", self.financing_type.is_some()),
+      ];
+      if let Some((field, _)) = filters.into_iter().find(|(_, set)| *set) {
+        return invalid(&format!("id cannot be combined with {field}"));
+      }
+    }
+    if self.financing_type.is_some() && self.institution_category.is_some() {
+      return invalid("financing_type only applies to university searches");
+    }
+    if self.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+      return invalid("name must not be blank");
+    }
+    Ok(())
+  }
+
+  /// Builds the URL that `operation` would request with these parameters, without sending it.
+  ///
+  /// `base` is joined with the endpoint path, so a trailing slash on it is ignored. The name
+  /// filter is applied after the response arrives and therefore is not part of the URL.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Operation, SearchParams};
+  ///
+  /// let url = SearchParams::new().with_id(41).url_for(Operation::University, "https://registry.edbo.gov.ua")?;
+  /// assert_eq!(url, "https://registry.edbo.gov.ua/api/university?id=41&exp=json");
+  /// # Ok::<(), libedbo::error::Error>(())
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the same errors as [`SearchParams::validate_for`].
+  pub fn url_for(&self, operation: Operation, base: &str) -> Result<String, Error> {
+    let query = self.query(operation)?;
+    Ok(format!("{}{}?{query}&exp=json", base.trim_end_matches('/'), operation.endpoint()))
+  }
+
+  /// Builds the URL of a university listing. See [`SearchParams::url_for`].
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Region, SearchParams, UniversityCategory};
+  ///
+  /// let url = SearchParams::new()
+  ///     .with_region(Region::LvivOblast)
+  ///     .with_university_category(UniversityCategory::HigherEducationInstitutions)
+  ///     .universities_url("https://registry.edbo.gov.ua")?;
+  /// assert_eq!(url, "https://registry.edbo.gov.ua/api/universities?ut=1&lc=46&exp=json");
+  /// # Ok::<(), libedbo::error::Error>(())
+  /// ```
+  pub fn universities_url(&self, base: &str) -> Result<String, Error> {
+    self.url_for(Operation::Universities, base)
+  }
+
+  /// Builds the URL of a single university. See [`SearchParams::url_for`].
+  pub fn university_url(&self, base: &str) -> Result<String, Error> {
+    self.url_for(Operation::University, base)
+  }
+
+  /// Builds the URL of an institution listing. See [`SearchParams::url_for`].
+  pub fn institutions_url(&self, base: &str) -> Result<String, Error> {
+    self.url_for(Operation::Institutions, base)
+  }
+
+  /// Builds the URL of a single school. See [`SearchParams::url_for`].
+  pub fn school_url(&self, base: &str) -> Result<String, Error> {
+    self.url_for(Operation::School, base)
+  }
+
+  /// Returns true if `university` passes the client-side name, financing type and close date
+  /// filters.
+  pub(crate) fn matches_university(&self, university: &UniversityBrief) -> bool {
+    self.name_matches(&[&university.university_name, &university.university_short_name])
+      && self.financing_matches(&university.university_financing_type_name)
+      && (self.include_closed || !university.is_closed())
+  }
+
+  /// Returns true if `institution` passes the client-side name filter.
+  pub(crate) fn matches_institution(&self, institution: &Institution) -> bool {
+    self.name_matches(&[&institution.institution_name, &institution.short_name])
+  }
+
+  /// Validates the parameters for `operation` and formats them as a query string.
+  fn query(&self, operation: Operation) -> Result<String, Error> {
+    match operation {
+      Operation::Universities => {
+        let ut = assert_some(self.university_category, "university_category")?;
+        let lc = assert_some(self.region, "region")?;
+        Ok(format!("ut={ut}&lc={lc}"))
+      }
+      Operation::Institutions => {
+        let ut = assert_some(self.institution_category, "institution_category")?;
+        let lc = assert_some(self.region, "region")?;
+        Ok(format!("ut={ut}&lc={lc}"))
+      }
+      Operation::University | Operation::School => {
+        let id = assert_some(self.id, "id")?;
+        if id < 1 {
+          let kind = if operation == Operation::University { "University" } else { "School" };
+          return Err(Error::OtherError(format!("{kind} ID must be positive")));
+        }
+        Ok(format!("id={id}"))
+      }
+    }
+  }
+
+  /// Returns true if no name filter is set or any of `names` contains the filter.
+  fn name_matches(&self, names: &[&str]) -> bool {
+    match &self.name {
+      None => true,
+      Some(needle) => {
+        let needle = fold_label(needle);
+        names.iter().any(|name| fold_label(name).contains(&needle))
+      }
+    }
+  }
+
+  /// Returns true if no financing type filter is set or `name` names the filtered type.
+  fn financing_matches(&self, name: &str) -> bool {
+    match &self.financing_type {
+      None => true,
+      Some(financing_type) => FinancingType::from_name(name) == *financing_type,
+    }
+  }
+}
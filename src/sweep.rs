@@ -0,0 +1,72 @@
+use crate::collation::{dedup_universities, sort_universities};
+use crate::error::Error;
+use crate::model::{Region, UniversityBrief};
+
+/// The outcome of a sweep over every region, which succeeds or fails region by region.
+///
+/// A region that fails does not discard the responses of the others: its error is kept in
+/// `failures` and the universities of every region that answered are in `universities`.
+#[derive(Debug)]
+pub struct SweepResult {
+  /// The universities of every region that answered, without duplicates.
+  pub universities: Vec<UniversityBrief>,
+  /// The regions that could not be listed, in region order, with the error each one hit.
+  pub failures: Vec<(Region, Error)>,
+}
+
+impl SweepResult {
+  /// Combines the per-region `responses`, given in [`Region::ALL`] order, dropping repeated
+  /// universities and sorting the rest by name if `sort` is set.
+  pub(crate) fn collect(responses: impl IntoIterator<Item = Result<Vec<UniversityBrief>, Error>>, sort: bool) -> SweepResult {
+    let mut universities = Vec::new();
+    let mut failures = Vec::new();
+    for (region, response) in Region::ALL.into_iter().zip(responses) {
+      match response {
+        Ok(found) => universities.extend(found),
+        Err(err) => failures.push((region, err)),
+      }
+    }
+    dedup_universities(&mut universities);
+    if sort {
+      sort_universities(&mut universities);
+    }
+    SweepResult { universities, failures }
+  }
+
+  /// Returns true if every region was listed.
+  pub fn is_complete(&self) -> bool {
+    self.failures.is_empty()
+  }
+
+  /// Returns the universities, discarding the failures, unless no region could be listed.
+  ///
+  /// # Examples
+  ///
+  /// ```rust
+  /// use libedbo::{Region, SweepResult};
+  /// use libedbo::error::Error;
+  ///
+  /// let partial = SweepResult { universities: Vec::new(), failures: vec![(Region::KyivCity, Error::Cancelled)] };
+  /// assert!(partial.into_result()?.is_empty());
+  ///
+  /// let failed = SweepResult {
+  ///     universities: Vec::new(),
+  ///     failures: Region::ALL.into_iter().map(|region| (region, Error::Cancelled)).collect(),
+  /// };
+  /// assert!(matches!(failed.into_result(), Err(Error::Cancelled)));
+  /// # Ok::<(), Error>(())
+  /// ```
+  ///
+  /// # Errors
+  ///
+  /// Returns the error of the first region if every region failed.
+  pub fn into_result(self) -> Result<Vec<UniversityBrief>, Error> {
+    if self.failures.len() < Region::ALL.len() {
+      return Ok(self.universities);
+    }
+    match self.failures.into_iter().next() {
+      Some((_, err)) => Err(err),
+      None => Ok(self.universities),
+    }
+  }
+}
@@ -0,0 +1,76 @@
+//! Request instrumentation. With the `tracing` feature disabled every method is a no-op.
+
+use std::future::Future;
+#[cfg(feature = "tracing")]
+use std::time::Instant;
+#[cfg(feature = "tracing")]
+use tracing::{field::Empty, Instrument, Span};
+
+use crate::error::Error;
+
+/// Tracks a single registry request for `tracing` subscribers.
+#[cfg(feature = "tracing")]
+pub(crate) struct RequestTrace {
+  span: Span,
+  started: Instant,
+}
+
+/// Tracks a single registry request for `tracing` subscribers.
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct RequestTrace;
+
+#[cfg(feature = "tracing")]
+impl RequestTrace {
+  /// Opens an `edbo_request` span recording the endpoint path and full URL of the request.
+  pub(crate) fn start(url: &str) -> Self {
+    let endpoint = reqwest::Url::parse(url).map(|url| url.path().to_string()).unwrap_or_default();
+    let span = tracing::debug_span!("edbo_request", endpoint, url, status = Empty, elapsed_ms = Empty);
+    RequestTrace { span, started: Instant::now() }
+  }
+
+  /// Runs `future` inside the request span.
+  pub(crate) async fn instrument<F: Future>(&self, future: F) -> F::Output {
+    future.instrument(self.span.clone()).await
+  }
+
+  /// Runs `f` inside the request span.
+  #[cfg(feature = "blocking")]
+  pub(crate) fn in_scope<T>(&self, f: impl FnOnce() -> T) -> T {
+    self.span.in_scope(f)
+  }
+
+  /// Records the HTTP status of the response.
+  pub(crate) fn status(&self, status: u16) {
+    self.span.record("status", status);
+  }
+
+  /// Records the elapsed time and emits an event describing the outcome.
+  pub(crate) fn finish<T>(self, result: &Result<T, Error>) {
+    let elapsed_ms = self.started.elapsed().as_millis() as u64;
+    self.span.record("elapsed_ms", elapsed_ms);
+    match result {
+      Ok(_) => tracing::debug!(parent: &self.span, elapsed_ms, "EDBO request succeeded"),
+      Err(err) => tracing::warn!(parent: &self.span, elapsed_ms, error = ?err, "EDBO request failed"),
+    }
+  }
+}
+
+#[cfg(not(feature = "tracing"))]
+impl RequestTrace {
+  pub(crate) fn start(_url: &str) -> Self {
+    RequestTrace
+  }
+
+  pub(crate) async fn instrument<F: Future>(&self, future: F) -> F::Output {
+    future.await
+  }
+
+  #[cfg(feature = "blocking")]
+  pub(crate) fn in_scope<T>(&self, f: impl FnOnce() -> T) -> T {
+    f()
+  }
+
+  pub(crate) fn status(&self, _status: u16) {}
+
+  pub(crate) fn finish<T>(self, _result: &Result<T, Error>) {}
+}
@@ -0,0 +1,164 @@
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture;
+use reqwest::Client;
+use serde_json::Value;
+
+use crate::error::Error;
+use crate::conditional::Validators;
+use crate::make_request;
+use crate::search::Operation;
+
+/// The future returned by [`HttpTransport::get_json`].
+///
+/// This is a `BoxFuture`, which must be `Send`. On wasm32 it is a `LocalBoxFuture` instead,
+/// because the browser's `fetch` futures cannot leave their thread.
+#[cfg(not(target_arch = "wasm32"))]
+pub type TransportFuture<'a> = BoxFuture<'a, Result<Value, Error>>;
+
+/// The future returned by [`HttpTransport::get_json`].
+#[cfg(target_arch = "wasm32")]
+pub type TransportFuture<'a> = LocalBoxFuture<'a, Result<Value, Error>>;
+
+/// The network layer an [`EdboClient`](crate::EdboClient) sends its requests through.
+///
+/// The default implementation, [`ReqwestTransport`], wraps a `reqwest::Client`. Supply your
+/// own implementation to use a different HTTP stack or to answer requests from canned data
+/// in tests. Retries and URL construction stay in the client, so a transport only has to
+/// perform a single GET and map the response to JSON or an [`Error`].
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::{EdboClient, HttpTransport, Region, SearchParams, TransportFuture, UniversityCategory};
+/// use libedbo::error::Error;
+/// use serde_json::{json, Value};
+///
+/// #[derive(Debug)]
+/// struct Canned;
+///
+/// impl HttpTransport for Canned {
+///     fn get_json<'a>(&'a self, _url: &'a str) -> TransportFuture<'a> {
+///         Box::pin(async { Ok(json!([])) })
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Error> {
+///     let client = EdboClient::builder().transport(Canned).build()?;
+///     let params = SearchParams::new()
+///         .with_region(Region::KyivCity)
+///         .with_university_category(UniversityCategory::HigherEducationInstitutions);
+///     assert!(client.search_universities(params).await?.is_empty());
+///     Ok(())
+/// }
+/// ```
+pub trait HttpTransport: fmt::Debug + Send + Sync {
+  /// Sends a GET request to `url` and returns the response body parsed as JSON.
+  ///
+  /// Implementations should map unsuccessful responses to the matching [`Error`] variants
+  /// (`NotFound` for 404, `RateLimited` for 429, `ApiError` otherwise) so that the client's
+  /// retry policy can recognize transient failures.
+  fn get_json<'a>(&'a self, url: &'a str) -> TransportFuture<'a>;
+
+  /// Sends a GET request like [`HttpTransport::get_json`], giving up once `timeout` has
+  /// passed. The timeout replaces any the transport applies to every request.
+  ///
+  /// The default implementation ignores `timeout` and calls `get_json`, so existing
+  /// transports keep working; [`ReqwestTransport`] applies it to the individual request.
+  fn get_json_with_timeout<'a>(&'a self, url: &'a str, timeout: Duration) -> TransportFuture<'a> {
+    let _ = timeout;
+    self.get_json(url)
+  }
+}
+
+/// The default [`HttpTransport`], backed by a `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+  http: Client,
+  validators: Option<Arc<Validators>>,
+}
+
+impl ReqwestTransport {
+  /// Creates a transport that sends requests through `http`.
+  pub fn new(http: Client) -> Self {
+    ReqwestTransport { http, validators: None }
+  }
+
+  /// Makes repeated requests for the same URL conditional. See
+  /// [`EdboClientBuilder::conditional_requests`](crate::EdboClientBuilder::conditional_requests).
+  ///
+  /// Clones of the transport share the remembered responses.
+  pub fn conditional_requests(mut self, enabled: bool) -> Self {
+    self.validators = enabled.then(Arc::default);
+    self
+  }
+}
+
+impl HttpTransport for ReqwestTransport {
+  fn get_json<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+    Box::pin(make_request(&self.http, url.to_string(), self.validators.as_deref(), None))
+  }
+
+  fn get_json_with_timeout<'a>(&'a self, url: &'a str, timeout: Duration) -> TransportFuture<'a> {
+    Box::pin(make_request(&self.http, url.to_string(), self.validators.as_deref(), Some(timeout)))
+  }
+}
+
+/// An [`HttpTransport`] that records the URLs a client would request instead of sending them.
+///
+/// Useful for testing code built on the client: the planned requests come back from
+/// [`DryRunTransport::planned_requests`] without any network traffic. Clones share the same
+/// record, so keep one clone and give the other to the client.
+///
+/// The results a dry-run client returns are placeholders. Listing searches yield empty lists,
+/// and single-record lookups fail with `Error::NotFound`, since there is nothing to return.
+///
+/// # Examples
+///
+/// ```rust
+/// use libedbo::{DryRunTransport, EdboClient, Region};
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), libedbo::error::Error> {
+/// let dry_run = DryRunTransport::new();
+/// let client = EdboClient::builder().transport(dry_run.clone()).retries(0).build()?;
+///
+/// let institutions = client.search_all_institution_types(Region::LvivOblast).await?;
+/// assert!(institutions.is_empty());
+/// assert_eq!(dry_run.planned_requests().len(), 3);
+/// assert!(dry_run.planned_requests()[0].contains("/api/institutions"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DryRunTransport {
+  planned: Arc<Mutex<Vec<String>>>,
+}
+
+impl DryRunTransport {
+  /// Creates a transport with no planned requests yet.
+  pub fn new() -> Self {
+    DryRunTransport::default()
+  }
+
+  /// Returns the URLs requested so far, in the order they were requested.
+  pub fn planned_requests(&self) -> Vec<String> {
+    self.planned.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+  }
+}
+
+impl HttpTransport for DryRunTransport {
+  fn get_json<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+    self.planned.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(url.to_string());
+    let response = match Operation::from_url(url) {
+      Some(Operation::University | Operation::School) => Err(Error::NotFound { id: None, kind: "resource" }),
+      _ => Ok(Value::Array(Vec::new())),
+    };
+    Box::pin(futures::future::ready(response))
+  }
+}
@@ -0,0 +1,28 @@
+use std::collections::HashSet;
+
+use crate::model::{University, UniversityId};
+
+/// A university together with the full records of its branches.
+///
+/// Returned by [`EdboClient::fetch_university_tree`](crate::EdboClient::fetch_university_tree).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UniversityTree {
+  /// The university the tree was fetched for.
+  pub root: University,
+  /// The full records of the root's branches, in the order the root lists them, each once.
+  pub branches: Vec<University>,
+}
+
+impl UniversityTree {
+  /// Returns the IDs of the root's branches that have to be fetched: every branch in order,
+  /// skipping repeats and the root itself.
+  pub(crate) fn branch_ids(root: &University) -> Vec<UniversityId> {
+    let mut seen = HashSet::from([root.university_id]);
+    root.branches.iter().map(|branch| branch.id()).filter(|id| seen.insert(*id)).collect()
+  }
+
+  /// Returns the root followed by its branches.
+  pub fn iter(&self) -> impl Iterator<Item = &University> {
+    std::iter::once(&self.root).chain(&self.branches)
+  }
+}